@@ -6,17 +6,54 @@ pub async fn get_node_definitions() -> Result<Vec<NodeDefinition>, AppError> {
     Ok(vec![
         NodeDefinition { node_type: "textInput".into(), label: "Text Input".into(), category: "input".into(), description: "Output a static text value".into() },
         NodeDefinition { node_type: "numberInput".into(), label: "Number Input".into(), category: "input".into(), description: "Output a static number value".into() },
-        NodeDefinition { node_type: "fileRead".into(), label: "File Read".into(), category: "input".into(), description: "Read contents from a file".into() },
+        NodeDefinition { node_type: "fileRead".into(), label: "File Read".into(), category: "input".into(), description: "Read text or binary contents from a file".into() },
         NodeDefinition { node_type: "httpRequest".into(), label: "HTTP Request".into(), category: "input".into(), description: "Make an HTTP request".into() },
-        NodeDefinition { node_type: "textTemplate".into(), label: "Text Template".into(), category: "transform".into(), description: "Interpolate variables into a template string".into() },
+        NodeDefinition { node_type: "uuid".into(), label: "UUID".into(), category: "input".into(), description: "Generate random (v4) or time-ordered (v7) UUIDs".into() },
+        NodeDefinition { node_type: "directoryList".into(), label: "Directory List".into(), category: "input".into(), description: "List the files in a folder, optionally recursive and filtered by glob patterns".into() },
+        NodeDefinition { node_type: "textTemplate".into(), label: "Text Template".into(), category: "transform".into(), description: "Render a Jinja template with loops, conditionals and filters".into() },
         NodeDefinition { node_type: "jsonParse".into(), label: "JSON Parse".into(), category: "transform".into(), description: "Parse a JSON string into an object".into() },
-        NodeDefinition { node_type: "regex".into(), label: "Regex".into(), category: "transform".into(), description: "Match or replace using regular expressions".into() },
+        NodeDefinition { node_type: "jsonSerialize".into(), label: "JSON Serialize".into(), category: "transform".into(), description: "Convert a value to pretty or compact JSON text".into() },
+        NodeDefinition { node_type: "regex".into(), label: "Regex".into(), category: "transform".into(), description: "Match, capture, replace or split using regular expressions".into() },
         NodeDefinition { node_type: "filter".into(), label: "Filter".into(), category: "transform".into(), description: "Filter array elements by condition".into() },
         NodeDefinition { node_type: "map".into(), label: "Map".into(), category: "transform".into(), description: "Transform each element in an array".into() },
         NodeDefinition { node_type: "merge".into(), label: "Merge".into(), category: "transform".into(), description: "Merge multiple inputs into one output".into() },
         NodeDefinition { node_type: "split".into(), label: "Split".into(), category: "transform".into(), description: "Split a string or array into parts".into() },
+        NodeDefinition { node_type: "csvParse".into(), label: "CSV Parse".into(), category: "transform".into(), description: "Parse CSV or TSV text into an array of rows".into() },
+        NodeDefinition { node_type: "csvSerialize".into(), label: "CSV Serialize".into(), category: "transform".into(), description: "Write an array of rows as CSV or TSV text".into() },
+        NodeDefinition { node_type: "yamlParse".into(), label: "YAML Parse".into(), category: "transform".into(), description: "Parse a YAML document into a value".into() },
+        NodeDefinition { node_type: "yamlSerialize".into(), label: "YAML Serialize".into(), category: "transform".into(), description: "Convert a value to YAML text".into() },
+        NodeDefinition { node_type: "tomlParse".into(), label: "TOML Parse".into(), category: "transform".into(), description: "Parse a TOML document into an object".into() },
+        NodeDefinition { node_type: "tomlSerialize".into(), label: "TOML Serialize".into(), category: "transform".into(), description: "Convert an object to TOML text".into() },
+        NodeDefinition { node_type: "xmlParse".into(), label: "XML Parse".into(), category: "transform".into(), description: "Parse XML into an object, mapping attributes and text to keys".into() },
+        NodeDefinition { node_type: "xmlSerialize".into(), label: "XML Serialize".into(), category: "transform".into(), description: "Convert an object to XML text".into() },
+        NodeDefinition { node_type: "query".into(), label: "Query".into(), category: "transform".into(), description: "Extract values with a JSONPath query".into() },
+        NodeDefinition { node_type: "sort".into(), label: "Sort".into(), category: "transform".into(), description: "Sort an array by a field".into() },
+        NodeDefinition { node_type: "groupBy".into(), label: "Group By".into(), category: "transform".into(), description: "Group array items by a key field".into() },
+        NodeDefinition { node_type: "aggregate".into(), label: "Aggregate".into(), category: "transform".into(), description: "Count, sum, average, min, max or collect distinct values per group".into() },
+        NodeDefinition { node_type: "dedupe".into(), label: "Dedupe".into(), category: "transform".into(), description: "Remove duplicate items by a key field".into() },
+        NodeDefinition { node_type: "join".into(), label: "Join".into(), category: "transform".into(), description: "Join two arrays of records on matching keys".into() },
+        NodeDefinition { node_type: "objectTransform".into(), label: "Object Transform".into(), category: "transform".into(), description: "Pick, omit, rename, set or flatten object fields".into() },
+        NodeDefinition { node_type: "flatten".into(), label: "Flatten".into(), category: "transform".into(), description: "Flatten nested arrays into one".into() },
+        NodeDefinition { node_type: "chunk".into(), label: "Chunk".into(), category: "transform".into(), description: "Split an array into batches of N items".into() },
+        NodeDefinition { node_type: "zip".into(), label: "Zip".into(), category: "transform".into(), description: "Pair up the items of two arrays".into() },
+        NodeDefinition { node_type: "slice".into(), label: "Slice".into(), category: "transform".into(), description: "Take or skip items of an array".into() },
+        NodeDefinition { node_type: "reverse".into(), label: "Reverse".into(), category: "transform".into(), description: "Reverse the order of an array".into() },
+        NodeDefinition { node_type: "unique".into(), label: "Unique".into(), category: "transform".into(), description: "Distinct values of an array or field".into() },
+        NodeDefinition { node_type: "length".into(), label: "Length".into(), category: "transform".into(), description: "Count items, characters or fields".into() },
+        NodeDefinition { node_type: "reduce".into(), label: "Reduce".into(), category: "transform".into(), description: "Fold an array into one value with an accumulator".into() },
+        NodeDefinition { node_type: "textTransform".into(), label: "Text Transform".into(), category: "transform".into(), description: "Change case, trim, pad, cut, replace or slugify text".into() },
+        NodeDefinition { node_type: "math".into(), label: "Math".into(), category: "transform".into(), description: "Calculate with numbers and format the result".into() },
+        NodeDefinition { node_type: "dateTime".into(), label: "Date & Time".into(), category: "transform".into(), description: "Get, parse, format, shift, compare or convert dates".into() },
+        NodeDefinition { node_type: "encode".into(), label: "Encode / Decode".into(), category: "transform".into(), description: "Convert text or bytes to and from base64, hex or URL encoding".into() },
+        NodeDefinition { node_type: "hash".into(), label: "Hash".into(), category: "transform".into(), description: "Compute a SHA-256, SHA-512, SHA-1, MD5 or BLAKE3 digest".into() },
+        NodeDefinition { node_type: "hmac".into(), label: "HMAC".into(), category: "transform".into(), description: "Sign data with a secret key using HMAC".into() },
+        NodeDefinition { node_type: "compress".into(), label: "Compress".into(), category: "transform".into(), description: "Compress or decompress data with gzip or zstd".into() },
+        NodeDefinition { node_type: "archiveExtract".into(), label: "Archive Extract".into(), category: "transform".into(), description: "List or extract the files in a zip or tar archive".into() },
+        NodeDefinition { node_type: "archiveCreate".into(), label: "Archive Create".into(), category: "transform".into(), description: "Build a zip or tar archive from name and content pairs".into() },
         NodeDefinition { node_type: "debug".into(), label: "Debug".into(), category: "output".into(), description: "Display incoming data for inspection".into() },
         NodeDefinition { node_type: "fileWrite".into(), label: "File Write".into(), category: "output".into(), description: "Write content to a file".into() },
+        NodeDefinition { node_type: "fileOps".into(), label: "File Ops".into(), category: "output".into(), description: "Copy, move, delete, create folders, check or inspect files".into() },
         NodeDefinition { node_type: "conditional".into(), label: "Conditional".into(), category: "control".into(), description: "Route data based on a condition".into() },
+        NodeDefinition { node_type: "code".into(), label: "Code".into(), category: "code".into(), description: "Run a custom Rhai script".into() },
     ])
 }
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -8,13 +8,25 @@ use crate::types::NodeValue;
 pub struct ExecutionContext {
     pub node_outputs: Arc<RwLock<HashMap<String, HashMap<String, NodeValue>>>>,
     pub cancelled: Arc<AtomicBool>,
+    // Reads of each node's outputs still to come. Outputs are dropped after
+    // their last read; nodes without consumers keep theirs until the run ends.
+    pub pending_consumers: Arc<RwLock<HashMap<String, usize>>>,
+    retained_bytes: AtomicUsize,
+    peak_retained_bytes: AtomicUsize,
 }
 
 impl ExecutionContext {
     pub fn new() -> Self {
+        Self::with_cancel_flag(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn with_cancel_flag(cancelled: Arc<AtomicBool>) -> Self {
         Self {
             node_outputs: Arc::new(RwLock::new(HashMap::new())),
-            cancelled: Arc::new(AtomicBool::new(false)),
+            cancelled,
+            pending_consumers: Arc::new(RwLock::new(HashMap::new())),
+            retained_bytes: AtomicUsize::new(0),
+            peak_retained_bytes: AtomicUsize::new(0),
         }
     }
 
@@ -26,9 +38,24 @@ impl ExecutionContext {
         self.cancelled.store(true, Ordering::Relaxed);
     }
 
+    pub async fn set_consumer_counts(&self, counts: HashMap<String, usize>) {
+        *self.pending_consumers.write().await = counts;
+    }
+
+    pub fn peak_retained_bytes(&self) -> usize {
+        self.peak_retained_bytes.load(Ordering::Relaxed)
+    }
+
     pub async fn store_output(&self, node_id: &str, outputs: HashMap<String, NodeValue>) {
+        let size = outputs_size(&outputs);
         let mut lock = self.node_outputs.write().await;
-        lock.insert(node_id.to_string(), outputs);
+        if let Some(previous) = lock.insert(node_id.to_string(), outputs) {
+            self.retained_bytes
+                .fetch_sub(outputs_size(&previous), Ordering::Relaxed);
+        }
+        let retained = self.retained_bytes.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_retained_bytes
+            .fetch_max(retained, Ordering::Relaxed);
     }
 
     pub async fn get_input(
@@ -42,4 +69,72 @@ impl ExecutionContext {
             .cloned()
             .unwrap_or(NodeValue::Null)
     }
+
+    // Reads an input over one edge. The last read of a node's outputs moves
+    // the value out and frees the rest instead of cloning.
+    pub async fn take_input(&self, source_node_id: &str, source_handle: &str) -> NodeValue {
+        let last_read = {
+            let mut consumers = self.pending_consumers.write().await;
+            match consumers.get_mut(source_node_id) {
+                Some(remaining) if *remaining > 0 => {
+                    *remaining -= 1;
+                    *remaining == 0
+                }
+                _ => false,
+            }
+        };
+        if !last_read {
+            return self.get_input(source_node_id, source_handle).await;
+        }
+
+        let mut lock = self.node_outputs.write().await;
+        let Some(mut outputs) = lock.remove(source_node_id) else {
+            return NodeValue::Null;
+        };
+        self.retained_bytes
+            .fetch_sub(outputs_size(&outputs), Ordering::Relaxed);
+        outputs.remove(source_handle).unwrap_or(NodeValue::Null)
+    }
+}
+
+fn outputs_size(outputs: &HashMap<String, NodeValue>) -> usize {
+    outputs
+        .iter()
+        .map(|(k, v)| k.capacity() + v.estimated_size())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_outputs_released_after_last_consumer() {
+        let ctx = ExecutionContext::new();
+        ctx.set_consumer_counts(HashMap::from([("a".to_string(), 2), ("b".to_string(), 0)]))
+            .await;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("value".to_string(), NodeValue::String("x".repeat(1000)));
+        ctx.store_output("a", outputs).await;
+        ctx.store_output("b", HashMap::new()).await;
+        let peak = ctx.peak_retained_bytes();
+        assert!(peak >= 1000);
+
+        assert_eq!(
+            ctx.take_input("a", "value").await.coerce_to_string().len(),
+            1000
+        );
+        assert!(ctx.node_outputs.read().await.contains_key("a"));
+        assert_eq!(
+            ctx.take_input("a", "value").await.coerce_to_string().len(),
+            1000
+        );
+        assert!(!ctx.node_outputs.read().await.contains_key("a"));
+
+        // Sink outputs stay, and the peak is unaffected by the release
+        assert!(ctx.node_outputs.read().await.contains_key("b"));
+        assert_eq!(ctx.peak_retained_bytes(), peak);
+        assert!(ctx.retained_bytes.load(Ordering::Relaxed) < 1000);
+    }
 }
@@ -0,0 +1,199 @@
+use encoding_rs::{CoderResult, DecoderResult, Encoding, UTF_8};
+
+// Text encodings accepted by nodes that convert between bytes and strings.
+// Besides the names below, any WHATWG label (e.g. "windows-1252", "shift_jis")
+// is accepted.
+pub const UTF8: &str = "utf-8";
+pub const LATIN1: &str = "latin-1";
+pub const AUTO: &str = "auto";
+
+pub fn decode_text(bytes: &[u8], encoding: &str) -> Result<String, String> {
+    match normalize(encoding).as_str() {
+        UTF8 => decode_utf8(bytes),
+        LATIN1 => Ok(bytes.iter().map(|&b| b as char).collect()),
+        AUTO => Ok(decode_auto(bytes)),
+        label => {
+            let encoding = lookup(label)?;
+            let (text, _, had_errors) = encoding.decode(bytes);
+            if had_errors {
+                return Err(format!("Content is not valid {}", encoding.name()));
+            }
+            Ok(text.into_owned())
+        }
+    }
+}
+
+pub fn encode_text(text: &str, encoding: &str) -> Result<Vec<u8>, String> {
+    match normalize(encoding).as_str() {
+        UTF8 | AUTO => Ok(text.as_bytes().to_vec()),
+        LATIN1 => text
+            .chars()
+            .map(|c| {
+                u8::try_from(c as u32)
+                    .map_err(|_| format!("Character '{}' cannot be encoded as Latin-1", c))
+            })
+            .collect(),
+        label => {
+            let encoding = lookup(label)?;
+            let (bytes, _, had_errors) = encoding.encode(text);
+            if had_errors {
+                return Err(format!("Text cannot be encoded as {}", encoding.name()));
+            }
+            Ok(bytes.into_owned())
+        }
+    }
+}
+
+fn normalize(encoding: &str) -> String {
+    match encoding.trim().to_lowercase().as_str() {
+        "" | "utf8" | "utf-8" => UTF8.to_string(),
+        "latin1" | "latin-1" | "iso-8859-1" | "iso8859-1" => LATIN1.to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn lookup(label: &str) -> Result<&'static Encoding, String> {
+    Encoding::for_label(label.as_bytes()).ok_or_else(|| format!("Unknown encoding '{}'", label))
+}
+
+fn decode_utf8(bytes: &[u8]) -> Result<String, String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    String::from_utf8(bytes.to_vec()).map_err(|e| {
+        format!(
+            "Content is not valid UTF-8 (invalid byte at offset {}); use binary mode or another encoding",
+            e.utf8_error().valid_up_to()
+        )
+    })
+}
+
+// A byte order mark wins; otherwise valid UTF-8 is taken as is and anything
+// else goes through charset detection.
+fn decode_auto(bytes: &[u8]) -> String {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return encoding
+            .decode_without_bom_handling(&bytes[bom_len..])
+            .0
+            .into_owned();
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return text.to_string();
+    }
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(None, true);
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+// Incremental counterpart of `decode_text` for reading in chunks. With `auto`
+// the encoding is sniffed from the first chunk.
+pub enum TextDecoder {
+    Latin1,
+    Whatwg {
+        decoder: encoding_rs::Decoder,
+        strict: bool,
+    },
+}
+
+impl TextDecoder {
+    pub fn new(encoding: &str, first_chunk: &[u8]) -> Result<Self, String> {
+        let (encoding, strict) = match normalize(encoding).as_str() {
+            UTF8 => (UTF_8, true),
+            LATIN1 => return Ok(TextDecoder::Latin1),
+            AUTO => (sniff(first_chunk), false),
+            label => (lookup(label)?, true),
+        };
+        Ok(TextDecoder::Whatwg {
+            decoder: encoding.new_decoder_with_bom_removal(),
+            strict,
+        })
+    }
+
+    pub fn decode_chunk(&mut self, bytes: &[u8], last: bool) -> Result<String, String> {
+        let (decoder, strict) = match self {
+            TextDecoder::Latin1 => return Ok(bytes.iter().map(|&b| b as char).collect()),
+            TextDecoder::Whatwg { decoder, strict } => (decoder, *strict),
+        };
+        let mut out = String::new();
+        let mut input = bytes;
+        loop {
+            out.reserve(
+                decoder
+                    .max_utf8_buffer_length(input.len())
+                    .unwrap_or(input.len() * 4 + 16),
+            );
+            let (done, read) = if strict {
+                let (result, read) =
+                    decoder.decode_to_string_without_replacement(input, &mut out, last);
+                match result {
+                    DecoderResult::InputEmpty => (true, read),
+                    DecoderResult::OutputFull => (false, read),
+                    DecoderResult::Malformed(..) => {
+                        return Err(format!(
+                            "Content is not valid {}; use binary mode or another encoding",
+                            decoder.encoding().name()
+                        ))
+                    }
+                }
+            } else {
+                let (result, read, _) = decoder.decode_to_string(input, &mut out, last);
+                (result == CoderResult::InputEmpty, read)
+            };
+            input = &input[read..];
+            if done {
+                return Ok(out);
+            }
+        }
+    }
+}
+
+fn sniff(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+    // A chunk may end in the middle of a multi-byte sequence
+    match std::str::from_utf8(bytes) {
+        Ok(_) => return UTF_8,
+        Err(e) if e.error_len().is_none() => return UTF_8,
+        Err(_) => {}
+    }
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, false);
+    detector.guess(None, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin1_round_trip() {
+        let bytes = encode_text("café", "latin-1").unwrap();
+        assert_eq!(bytes, b"caf\xE9");
+        assert_eq!(decode_text(&bytes, "ISO-8859-1").unwrap(), "café");
+        assert!(encode_text("€", "latin-1").is_err());
+    }
+
+    #[test]
+    fn test_chunked_decoding_handles_split_sequences() {
+        let bytes = "héllo wörld".as_bytes();
+        let mut decoder = TextDecoder::new("utf-8", bytes).unwrap();
+        let mut text = decoder.decode_chunk(&bytes[..2], false).unwrap();
+        text.push_str(&decoder.decode_chunk(&bytes[2..], true).unwrap());
+        assert_eq!(text, "héllo wörld");
+
+        let mut strict = TextDecoder::new("utf-8", b"").unwrap();
+        assert!(strict.decode_chunk(b"caf\xE9 ok", true).is_err());
+    }
+
+    #[test]
+    fn test_utf8_is_strict_and_auto_detects() {
+        assert!(decode_text(b"caf\xE9", "utf-8").is_err());
+        assert_eq!(decode_text(b"\xEF\xBB\xBFhi", "utf-8").unwrap(), "hi");
+        assert_eq!(decode_text("naïve".as_bytes(), "auto").unwrap(), "naïve");
+        assert_eq!(decode_text(b"\xFF\xFEh\x00i\x00", "auto").unwrap(), "hi");
+        assert_eq!(
+            decode_text(b"Le caf\xE9 est tr\xE8s bon", "auto").unwrap(),
+            "Le café est très bon"
+        );
+    }
+}
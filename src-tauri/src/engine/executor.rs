@@ -3,7 +3,6 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use tauri::ipc::Channel;
-use tokio::sync::RwLock;
 
 use crate::error::AppError;
 use crate::nodes::registry::NodeRegistry;
@@ -44,13 +43,13 @@ impl Engine {
         let start = Instant::now();
         let flow_graph = FlowGraph::from_document(doc)?;
 
-        let ctx = ExecutionContext {
-            node_outputs: Arc::new(RwLock::new(HashMap::new())),
-            cancelled: self.cancel_flag.clone(),
-        };
+        let ctx = ExecutionContext::with_cancel_flag(self.cancel_flag.clone());
+        ctx.set_consumer_counts(flow_graph.consumer_counts()).await;
 
         let node_map: HashMap<String, &FlowNode> =
             doc.nodes.iter().map(|n| (n.id.clone(), n)).collect();
+        self.validate_fan_in(doc, &flow_graph)?;
+        self.validate_configs(doc)?;
 
         let mut node_results: HashMap<String, NodeResult> = HashMap::new();
         let mut had_error = false;
@@ -74,13 +73,23 @@ impl Engine {
 
                 // Gather inputs from upstream node outputs
                 let input_edges = flow_graph.get_input_edges(node_id);
+                let multi_ports = executor.multi_input_ports();
                 let mut inputs = HashMap::new();
 
                 for edge in &input_edges {
                     let handle = edge.target_handle.as_deref().unwrap_or("input");
                     let source_handle = edge.source_handle.as_deref().unwrap_or("value");
-                    let value = ctx.get_input(&edge.source, source_handle).await;
-                    inputs.insert(handle.to_string(), value);
+                    let value = ctx.take_input(&edge.source, source_handle).await;
+                    if multi_ports.contains(&handle) {
+                        let entry = inputs
+                            .entry(handle.to_string())
+                            .or_insert_with(|| NodeValue::Array(Vec::new()));
+                        if let NodeValue::Array(values) = entry {
+                            values.push(value);
+                        }
+                    } else {
+                        inputs.insert(handle.to_string(), value);
+                    }
                 }
 
                 let _ = channel.send(ExecutionEvent::NodeStarted {
@@ -88,7 +97,14 @@ impl Engine {
                 });
 
                 let node_start = Instant::now();
-                let result = executor.execute(inputs, node.data.clone(), &ctx).await;
+                let result = if executor.accepts_streams() {
+                    executor.execute(inputs, node.data.clone(), &ctx).await
+                } else {
+                    match materialize_streams(inputs).await {
+                        Ok(inputs) => executor.execute(inputs, node.data.clone(), &ctx).await,
+                        Err(e) => Err(e),
+                    }
+                };
                 let duration_ms = node_start.elapsed().as_millis() as u64;
 
                 match result {
@@ -164,6 +180,7 @@ impl Engine {
         Ok(ExecutionResult {
             success: !had_error,
             total_duration_ms,
+            peak_retained_bytes: ctx.peak_retained_bytes() as u64,
             node_results,
             error: if had_error {
                 Some("One or more nodes failed".to_string())
@@ -172,4 +189,60 @@ impl Engine {
             },
         })
     }
+
+    // Several edges into a single-connection port would otherwise silently
+    // overwrite each other
+    fn validate_fan_in(&self, doc: &FlowDocument, flow_graph: &FlowGraph) -> Result<(), AppError> {
+        for node in &doc.nodes {
+            let Some(executor) = self.registry.get(&node.node_type) else {
+                continue;
+            };
+            let mut connected = std::collections::HashSet::new();
+            for edge in flow_graph.get_input_edges(&node.id) {
+                let handle = edge.target_handle.as_deref().unwrap_or("input");
+                if !connected.insert(handle) && !executor.multi_input_ports().contains(&handle) {
+                    return Err(AppError::Validation(format!(
+                        "Input '{}' of node {} has more than one connection but accepts only one",
+                        handle, node.id
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_configs(&self, doc: &FlowDocument) -> Result<(), AppError> {
+        for node in &doc.nodes {
+            if let Some(executor) = self.registry.get(&node.node_type) {
+                executor.validate_config(&node.data).map_err(|e| {
+                    let message = match e {
+                        AppError::Validation(message) => message,
+                        other => other.to_string(),
+                    };
+                    AppError::Validation(format!("Node {}: {}", node.id, message))
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn materialize_streams(
+    mut inputs: HashMap<String, NodeValue>,
+) -> Result<HashMap<String, NodeValue>, AppError> {
+    for value in inputs.values_mut() {
+        match value {
+            NodeValue::Stream(stream) => *value = stream.materialize().await?,
+            // Fan-in ports collect values into an array
+            NodeValue::Array(items) => {
+                for item in items.iter_mut() {
+                    if let NodeValue::Stream(stream) = item {
+                        *item = stream.materialize().await?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(inputs)
 }
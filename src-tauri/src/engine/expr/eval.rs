@@ -0,0 +1,352 @@
+use indexmap::IndexMap;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use super::functions;
+use super::parser::{Ast, BinaryOp, UnaryOp};
+use crate::types::NodeValue;
+
+pub fn evaluate(ast: &Ast, vars: &[(&str, &NodeValue)]) -> Result<NodeValue, String> {
+    match ast {
+        Ast::Null => Ok(NodeValue::Null),
+        Ast::Bool(b) => Ok(NodeValue::Boolean(*b)),
+        Ast::Integer(n) => Ok(NodeValue::Integer(*n)),
+        Ast::Number(n) => Ok(NodeValue::Number(*n)),
+        Ast::Str(s) => Ok(NodeValue::String(s.clone())),
+        Ast::Var(name) => variable(name, vars).cloned(),
+        Ast::Array(items) => Ok(NodeValue::Array(
+            items
+                .iter()
+                .map(|item| evaluate(item, vars))
+                .collect::<Result<_, _>>()?,
+        )),
+        Ast::Object(fields) => {
+            let mut obj = IndexMap::new();
+            for (key, value) in fields {
+                obj.insert(key.clone(), evaluate(value, vars)?);
+            }
+            Ok(NodeValue::Object(obj))
+        }
+        Ast::Member(target, name) => Ok(member(operand(target, vars)?.as_ref(), name)),
+        Ast::Index(target, index) => {
+            let target = operand(target, vars)?;
+            let index = operand(index, vars)?;
+            Ok(index_value(&target, &index))
+        }
+        Ast::Call(name, args) => {
+            let args = args
+                .iter()
+                .map(|arg| evaluate(arg, vars))
+                .collect::<Result<Vec<_>, _>>()?;
+            functions::call(name, args)
+        }
+        Ast::Unary(op, operand) => {
+            let value = self::operand(operand, vars)?;
+            match op {
+                UnaryOp::Not => Ok(NodeValue::Boolean(!is_truthy(&value))),
+                UnaryOp::Neg => Ok(match expect_number(&value, "-")? {
+                    Num::Int(i) => i
+                        .checked_neg()
+                        .map(NodeValue::Integer)
+                        .unwrap_or(NodeValue::Number(-(i as f64))),
+                    Num::Float(f) => NodeValue::Number(-f),
+                }),
+            }
+        }
+        Ast::Binary(op, left, right) => {
+            let left = operand(left, vars)?;
+            let right = operand(right, vars)?;
+            binary(*op, &left, &right)
+        }
+        Ast::And(left, right) => {
+            let left = evaluate(left, vars)?;
+            if is_truthy(&left) {
+                evaluate(right, vars)
+            } else {
+                Ok(left)
+            }
+        }
+        Ast::Or(left, right) => {
+            let left = evaluate(left, vars)?;
+            if is_truthy(&left) {
+                Ok(left)
+            } else {
+                evaluate(right, vars)
+            }
+        }
+        Ast::Coalesce(left, right) => match evaluate(left, vars)? {
+            NodeValue::Null => evaluate(right, vars),
+            other => Ok(other),
+        },
+        Ast::Ternary(condition, then, otherwise) => {
+            if is_truthy(&evaluate(condition, vars)?) {
+                evaluate(then, vars)
+            } else {
+                evaluate(otherwise, vars)
+            }
+        }
+    }
+}
+
+fn variable<'a>(name: &str, vars: &[(&str, &'a NodeValue)]) -> Result<&'a NodeValue, String> {
+    vars.iter()
+        .find(|(k, _)| *k == name)
+        .map(|(_, v)| *v)
+        .ok_or_else(|| format!("Unknown variable '{}'", name))
+}
+
+// Operands that are plain variables are borrowed, so reading `acc[key]` or
+// `acc.length` does not copy the whole value first
+fn operand<'a>(ast: &Ast, vars: &[(&str, &'a NodeValue)]) -> Result<Cow<'a, NodeValue>, String> {
+    match ast {
+        Ast::Var(name) => variable(name, vars).map(Cow::Borrowed),
+        _ => evaluate(ast, vars).map(Cow::Owned),
+    }
+}
+
+// Evaluates with `name` bound to an owned value. When that value is the
+// expression itself, the first argument of a call such as `set(acc, k, v)`,
+// or a branch of a ternary, it is moved rather than cloned, so a fold that
+// grows an object or array stays linear.
+pub fn evaluate_owned(
+    ast: &Ast,
+    name: &str,
+    value: NodeValue,
+    vars: &[(&str, &NodeValue)],
+) -> Result<NodeValue, String> {
+    match ast {
+        Ast::Var(var) if var == name => Ok(value),
+        Ast::Call(function, args) if matches!(args.first(), Some(Ast::Var(var)) if var == name) => {
+            let rest = {
+                let scope = scope_with(name, &value, vars);
+                args[1..]
+                    .iter()
+                    .map(|arg| evaluate(arg, &scope))
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+            functions::call(function, std::iter::once(value).chain(rest).collect())
+        }
+        Ast::Ternary(condition, then, otherwise) => {
+            let branch = if is_truthy(&evaluate(condition, &scope_with(name, &value, vars))?) {
+                then
+            } else {
+                otherwise
+            };
+            evaluate_owned(branch, name, value, vars)
+        }
+        _ => evaluate(ast, &scope_with(name, &value, vars)),
+    }
+}
+
+fn scope_with<'a>(
+    name: &'a str,
+    value: &'a NodeValue,
+    vars: &[(&'a str, &'a NodeValue)],
+) -> Vec<(&'a str, &'a NodeValue)> {
+    std::iter::once((name, value))
+        .chain(vars.iter().copied())
+        .collect()
+}
+
+pub fn is_truthy(value: &NodeValue) -> bool {
+    value.as_bool().unwrap_or(false)
+}
+
+// Missing fields evaluate to null rather than failing, so conditions such as
+// `item.address.city == 'Paris'` work on heterogeneous records.
+fn member(target: &NodeValue, name: &str) -> NodeValue {
+    match target {
+        NodeValue::Object(obj) => obj.get(name).cloned().unwrap_or(NodeValue::Null),
+        NodeValue::File { path } if name == "path" => NodeValue::String(path.clone()),
+        NodeValue::Array(arr) if name == "length" => NodeValue::Integer(arr.len() as i64),
+        NodeValue::String(s) if name == "length" => NodeValue::Integer(s.chars().count() as i64),
+        _ => NodeValue::Null,
+    }
+}
+
+fn index_value(target: &NodeValue, index: &NodeValue) -> NodeValue {
+    if let NodeValue::String(key) = index {
+        return member(target, key);
+    }
+    let Some(n) = index
+        .as_integer()
+        .filter(|_| !matches!(index, NodeValue::Boolean(_)))
+    else {
+        return NodeValue::Null;
+    };
+    match target {
+        NodeValue::Array(arr) => resolve_index(n, arr.len())
+            .and_then(|i| arr.get(i).cloned())
+            .unwrap_or(NodeValue::Null),
+        NodeValue::String(s) => resolve_index(n, s.chars().count())
+            .and_then(|i| s.chars().nth(i))
+            .map(|c| NodeValue::String(c.to_string()))
+            .unwrap_or(NodeValue::Null),
+        _ => NodeValue::Null,
+    }
+}
+
+// Negative indexes count from the end, as in Python
+pub fn resolve_index(n: i64, len: usize) -> Option<usize> {
+    let i = if n < 0 { len as i64 + n } else { n };
+    if i < 0 || i >= len as i64 {
+        None
+    } else {
+        Some(i as usize)
+    }
+}
+
+fn binary(op: BinaryOp, left: &NodeValue, right: &NodeValue) -> Result<NodeValue, String> {
+    let result =
+        match op {
+            BinaryOp::Add => match (left, right) {
+                (NodeValue::Array(a), NodeValue::Array(b)) => {
+                    NodeValue::Array(a.iter().chain(b.iter()).cloned().collect())
+                }
+                (NodeValue::String(_), _) | (_, NodeValue::String(_)) => NodeValue::String(
+                    format!("{}{}", left.coerce_to_string(), right.coerce_to_string()),
+                ),
+                _ => arithmetic(op, left, right, "+")?,
+            },
+            BinaryOp::Sub => arithmetic(op, left, right, "-")?,
+            BinaryOp::Mul => arithmetic(op, left, right, "*")?,
+            BinaryOp::Div => arithmetic(op, left, right, "/")?,
+            BinaryOp::Rem => arithmetic(op, left, right, "%")?,
+            BinaryOp::Eq => NodeValue::Boolean(values_equal(left, right)),
+            BinaryOp::Ne => NodeValue::Boolean(!values_equal(left, right)),
+            BinaryOp::Lt => compare(left, right, |o| o == Ordering::Less)?,
+            BinaryOp::Le => compare(left, right, |o| o != Ordering::Greater)?,
+            BinaryOp::Gt => compare(left, right, |o| o == Ordering::Greater)?,
+            BinaryOp::Ge => compare(left, right, |o| o != Ordering::Less)?,
+            BinaryOp::In => NodeValue::Boolean(functions::contains(right, left)),
+        };
+    Ok(result)
+}
+
+#[derive(Clone, Copy)]
+enum Num {
+    Int(i64),
+    Float(f64),
+}
+
+impl Num {
+    fn as_f64(self) -> f64 {
+        match self {
+            Num::Int(i) => i as f64,
+            Num::Float(f) => f,
+        }
+    }
+}
+
+// Integer arithmetic stays exact; it only widens to float on overflow or
+// when a division has a remainder.
+fn arithmetic(
+    op: BinaryOp,
+    left: &NodeValue,
+    right: &NodeValue,
+    symbol: &str,
+) -> Result<NodeValue, String> {
+    let a = expect_number(left, symbol)?;
+    let b = expect_number(right, symbol)?;
+
+    if matches!(op, BinaryOp::Div | BinaryOp::Rem) && b.as_f64() == 0.0 {
+        return Err("Division by zero".to_string());
+    }
+
+    if let (Num::Int(x), Num::Int(y)) = (a, b) {
+        let exact = match op {
+            BinaryOp::Add => x.checked_add(y),
+            BinaryOp::Sub => x.checked_sub(y),
+            BinaryOp::Mul => x.checked_mul(y),
+            BinaryOp::Div if x.checked_rem(y) == Some(0) => x.checked_div(y),
+            BinaryOp::Rem => x.checked_rem(y),
+            _ => None,
+        };
+        if let Some(n) = exact {
+            return Ok(NodeValue::Integer(n));
+        }
+    }
+
+    let (x, y) = (a.as_f64(), b.as_f64());
+    Ok(NodeValue::Number(match op {
+        BinaryOp::Add => x + y,
+        BinaryOp::Sub => x - y,
+        BinaryOp::Mul => x * y,
+        BinaryOp::Div => x / y,
+        _ => x % y,
+    }))
+}
+
+fn expect_number(value: &NodeValue, op: &str) -> Result<Num, String> {
+    match value {
+        NodeValue::Integer(i) => Ok(Num::Int(*i)),
+        NodeValue::Number(n) => Ok(Num::Float(*n)),
+        NodeValue::Boolean(b) => Ok(Num::Int(*b as i64)),
+        NodeValue::String(s) => match NodeValue::parse_number(s) {
+            Some(NodeValue::Integer(i)) => Ok(Num::Int(i)),
+            Some(NodeValue::Number(n)) => Ok(Num::Float(n)),
+            _ => Err(format!("'{}' is not a number (operator '{}')", s, op)),
+        },
+        other => Err(format!(
+            "Cannot apply '{}' to {}",
+            op,
+            functions::type_name(other)
+        )),
+    }
+}
+
+pub fn values_equal(a: &NodeValue, b: &NodeValue) -> bool {
+    match (a, b) {
+        (NodeValue::Null, NodeValue::Null) => true,
+        (NodeValue::Boolean(x), NodeValue::Boolean(y)) => x == y,
+        (NodeValue::Integer(x), NodeValue::Integer(y)) => x == y,
+        (
+            NodeValue::Integer(_) | NodeValue::Number(_),
+            NodeValue::Integer(_) | NodeValue::Number(_),
+        ) => a.as_number() == b.as_number(),
+        (NodeValue::String(x), NodeValue::String(y)) => x == y,
+        (NodeValue::Array(x), NodeValue::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y).all(|(a, b)| values_equal(a, b))
+        }
+        (NodeValue::Object(x), NodeValue::Object(y)) => {
+            x.len() == y.len()
+                && x.iter()
+                    .all(|(k, v)| y.get(k).is_some_and(|other| values_equal(v, other)))
+        }
+        (NodeValue::File { path: x }, NodeValue::File { path: y }) => x == y,
+        (NodeValue::Bytes(x), NodeValue::Bytes(y)) => x == y,
+        _ => false,
+    }
+}
+
+// Comparing against null (e.g. a missing field) is false rather than an error
+fn compare(
+    a: &NodeValue,
+    b: &NodeValue,
+    predicate: impl Fn(Ordering) -> bool,
+) -> Result<NodeValue, String> {
+    if matches!(a, NodeValue::Null) || matches!(b, NodeValue::Null) {
+        return Ok(NodeValue::Boolean(false));
+    }
+    let ordering = compare_values(a, b).ok_or_else(|| {
+        format!(
+            "Cannot compare {} with {}",
+            functions::type_name(a),
+            functions::type_name(b)
+        )
+    })?;
+    Ok(NodeValue::Boolean(predicate(ordering)))
+}
+
+pub fn compare_values(a: &NodeValue, b: &NodeValue) -> Option<Ordering> {
+    match (a, b) {
+        (NodeValue::Integer(x), NodeValue::Integer(y)) => Some(x.cmp(y)),
+        (NodeValue::String(x), NodeValue::String(y)) => Some(x.cmp(y)),
+        (NodeValue::Boolean(x), NodeValue::Boolean(y)) => Some(x.cmp(y)),
+        (
+            NodeValue::Integer(_) | NodeValue::Number(_) | NodeValue::String(_),
+            NodeValue::Integer(_) | NodeValue::Number(_) | NodeValue::String(_),
+        ) => a.as_number()?.partial_cmp(&b.as_number()?),
+        _ => None,
+    }
+}
@@ -0,0 +1,406 @@
+use super::eval::values_equal;
+use crate::types::NodeValue;
+
+const FUNCTIONS: &[&str] = &[
+    "len",
+    "length",
+    "upper",
+    "toUpperCase",
+    "lower",
+    "toLowerCase",
+    "trim",
+    "trimStart",
+    "trimEnd",
+    "contains",
+    "includes",
+    "startsWith",
+    "endsWith",
+    "replace",
+    "split",
+    "join",
+    "substring",
+    "indexOf",
+    "str",
+    "toString",
+    "number",
+    "bool",
+    "round",
+    "floor",
+    "ceil",
+    "abs",
+    "min",
+    "max",
+    "pow",
+    "sqrt",
+    "keys",
+    "values",
+    "set",
+    "merge",
+    "type",
+    "typeof",
+    "isNull",
+    "isEmpty",
+    "matches",
+    "int",
+];
+
+pub fn exists(name: &str) -> bool {
+    FUNCTIONS.contains(&name)
+}
+
+pub fn call(name: &str, args: Vec<NodeValue>) -> Result<NodeValue, String> {
+    let result = match name {
+        "len" | "length" => {
+            let [value] = fixed_args(name, args)?;
+            NodeValue::Integer(match &value {
+                NodeValue::String(s) => s.chars().count(),
+                NodeValue::Array(arr) => arr.len(),
+                NodeValue::Object(obj) => obj.len(),
+                NodeValue::Bytes(bytes) => bytes.len(),
+                NodeValue::Null => 0,
+                other => other.coerce_to_string().chars().count(),
+            } as i64)
+        }
+        "upper" | "toUpperCase" => map_string(name, args, |s| s.to_uppercase())?,
+        "lower" | "toLowerCase" => map_string(name, args, |s| s.to_lowercase())?,
+        "trim" => map_string(name, args, |s| s.trim().to_string())?,
+        "trimStart" => map_string(name, args, |s| s.trim_start().to_string())?,
+        "trimEnd" => map_string(name, args, |s| s.trim_end().to_string())?,
+        "contains" | "includes" => {
+            let [haystack, needle] = fixed_args(name, args)?;
+            NodeValue::Boolean(contains(&haystack, &needle))
+        }
+        "startsWith" => {
+            let [s, prefix] = fixed_args(name, args)?;
+            NodeValue::Boolean(s.coerce_to_string().starts_with(&prefix.coerce_to_string()))
+        }
+        "endsWith" => {
+            let [s, suffix] = fixed_args(name, args)?;
+            NodeValue::Boolean(s.coerce_to_string().ends_with(&suffix.coerce_to_string()))
+        }
+        "replace" => {
+            let [s, from, to] = fixed_args(name, args)?;
+            NodeValue::String(
+                s.coerce_to_string()
+                    .replace(&from.coerce_to_string(), &to.coerce_to_string()),
+            )
+        }
+        "split" => {
+            let [s, separator] = fixed_args(name, args)?;
+            NodeValue::Array(
+                s.coerce_to_string()
+                    .split(&separator.coerce_to_string())
+                    .map(|part| NodeValue::String(part.to_string()))
+                    .collect(),
+            )
+        }
+        "join" => {
+            let [arr, separator] = fixed_args(name, args)?;
+            let NodeValue::Array(items) = arr else {
+                return Err(format!("join() expects an array, got {}", type_name(&arr)));
+            };
+            NodeValue::String(
+                items
+                    .iter()
+                    .map(|v| v.coerce_to_string())
+                    .collect::<Vec<_>>()
+                    .join(&separator.coerce_to_string()),
+            )
+        }
+        "substring" => {
+            check_arity(name, &args, 2, 3)?;
+            let s = args[0].coerce_to_string();
+            let len = s.chars().count();
+            let start = (number_arg(name, &args[1])?.max(0.0) as usize).min(len);
+            let end = match args.get(2) {
+                Some(end) => (number_arg(name, end)?.max(0.0) as usize).min(len),
+                None => len,
+            };
+            NodeValue::String(
+                s.chars()
+                    .skip(start)
+                    .take(end.saturating_sub(start))
+                    .collect(),
+            )
+        }
+        "indexOf" => {
+            let [haystack, needle] = fixed_args(name, args)?;
+            let index = match &haystack {
+                NodeValue::Array(arr) => arr
+                    .iter()
+                    .position(|v| values_equal(v, &needle))
+                    .map(|i| i as i64),
+                other => {
+                    let s = other.coerce_to_string();
+                    s.find(&needle.coerce_to_string())
+                        .map(|byte| s[..byte].chars().count() as i64)
+                }
+            };
+            NodeValue::Integer(index.unwrap_or(-1))
+        }
+        "str" | "toString" => {
+            let [value] = fixed_args(name, args)?;
+            NodeValue::String(value.coerce_to_string())
+        }
+        "number" => {
+            let [value] = fixed_args(name, args)?;
+            match value {
+                NodeValue::Integer(_) | NodeValue::Number(_) => value,
+                NodeValue::String(s) => NodeValue::parse_number(&s).unwrap_or(NodeValue::Null),
+                other => other
+                    .as_number()
+                    .map(NodeValue::Number)
+                    .unwrap_or(NodeValue::Null),
+            }
+        }
+        "int" => {
+            let [value] = fixed_args(name, args)?;
+            match value.as_number() {
+                Some(n) if n.is_finite() && n.abs() < i64::MAX as f64 => {
+                    NodeValue::Integer(value.as_integer().unwrap_or(n.trunc() as i64))
+                }
+                _ => NodeValue::Null,
+            }
+        }
+        "bool" => {
+            let [value] = fixed_args(name, args)?;
+            NodeValue::Boolean(value.as_bool().unwrap_or(false))
+        }
+        "round" => {
+            check_arity(name, &args, 1, 2)?;
+            let n = number_arg(name, &args[0])?;
+            match args.get(1) {
+                Some(d) => {
+                    let factor = 10f64.powi(number_arg(name, d)? as i32);
+                    NodeValue::Number((n * factor).round() / factor)
+                }
+                None => integral(n.round()),
+            }
+        }
+        "floor" => integral(number_arg(name, &single_arg(name, args)?)?.floor()),
+        "ceil" => integral(number_arg(name, &single_arg(name, args)?)?.ceil()),
+        "abs" => match single_arg(name, args)? {
+            NodeValue::Integer(i) if i != i64::MIN => NodeValue::Integer(i.abs()),
+            other => NodeValue::Number(number_arg(name, &other)?.abs()),
+        },
+        "sqrt" => NodeValue::Number(number_arg(name, &single_arg(name, args)?)?.sqrt()),
+        "pow" => {
+            let [base, exponent] = fixed_args(name, args)?;
+            let exact = match (&base, &exponent) {
+                (NodeValue::Integer(b), NodeValue::Integer(e)) if *e >= 0 => {
+                    u32::try_from(*e).ok().and_then(|e| b.checked_pow(e))
+                }
+                _ => None,
+            };
+            match exact {
+                Some(n) => NodeValue::Integer(n),
+                None => {
+                    NodeValue::Number(number_arg(name, &base)?.powf(number_arg(name, &exponent)?))
+                }
+            }
+        }
+        "min" | "max" => {
+            // Accepts either several numbers or a single array of numbers
+            let values = match args.as_slice() {
+                [NodeValue::Array(arr)] => arr.clone(),
+                _ => args,
+            };
+            if values.is_empty() {
+                return Err(format!("{}() needs at least one value", name));
+            }
+            if values.iter().all(|v| matches!(v, NodeValue::Integer(_))) {
+                let ints = values.iter().filter_map(|v| v.as_integer());
+                let result = if name == "min" {
+                    ints.min()
+                } else {
+                    ints.max()
+                };
+                return Ok(result.map(NodeValue::Integer).unwrap_or(NodeValue::Null));
+            }
+            let numbers = values
+                .iter()
+                .map(|v| number_arg(name, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            NodeValue::Number(numbers[1..].iter().fold(numbers[0], |acc, &n| {
+                if name == "min" {
+                    acc.min(n)
+                } else {
+                    acc.max(n)
+                }
+            }))
+        }
+        "keys" => {
+            let [value] = fixed_args(name, args)?;
+            match value {
+                NodeValue::Object(obj) => {
+                    NodeValue::Array(obj.into_keys().map(NodeValue::String).collect())
+                }
+                other => {
+                    return Err(format!(
+                        "keys() expects an object, got {}",
+                        type_name(&other)
+                    ))
+                }
+            }
+        }
+        "values" => {
+            let [value] = fixed_args(name, args)?;
+            match value {
+                NodeValue::Object(obj) => NodeValue::Array(obj.into_values().collect()),
+                other => {
+                    return Err(format!(
+                        "values() expects an object, got {}",
+                        type_name(&other)
+                    ))
+                }
+            }
+        }
+        // Objects are values, so these return an updated copy, e.g.
+        // `set(acc, item.id, item)` in a Reduce building a lookup table
+        "set" => {
+            let [target, key, value] = fixed_args(name, args)?;
+            match target {
+                NodeValue::Object(mut obj) => {
+                    obj.insert(key.coerce_to_string(), value);
+                    NodeValue::Object(obj)
+                }
+                NodeValue::Null => {
+                    NodeValue::Object([(key.coerce_to_string(), value)].into_iter().collect())
+                }
+                other => {
+                    return Err(format!(
+                        "set() expects an object, got {}",
+                        type_name(&other)
+                    ))
+                }
+            }
+        }
+        "merge" => {
+            let mut merged = indexmap::IndexMap::new();
+            for value in args {
+                match value {
+                    NodeValue::Object(obj) => merged.extend(obj),
+                    NodeValue::Null => {}
+                    other => {
+                        return Err(format!(
+                            "merge() expects objects, got {}",
+                            type_name(&other)
+                        ))
+                    }
+                }
+            }
+            NodeValue::Object(merged)
+        }
+        "type" | "typeof" => {
+            let [value] = fixed_args(name, args)?;
+            NodeValue::String(type_name(&value).to_string())
+        }
+        "isNull" => {
+            let [value] = fixed_args(name, args)?;
+            NodeValue::Boolean(matches!(value, NodeValue::Null))
+        }
+        "isEmpty" => {
+            let [value] = fixed_args(name, args)?;
+            NodeValue::Boolean(match &value {
+                NodeValue::Null => true,
+                NodeValue::String(s) => s.is_empty(),
+                NodeValue::Array(arr) => arr.is_empty(),
+                NodeValue::Object(obj) => obj.is_empty(),
+                NodeValue::Bytes(bytes) => bytes.is_empty(),
+                _ => false,
+            })
+        }
+        "matches" => {
+            let [s, pattern] = fixed_args(name, args)?;
+            let re = regex::Regex::new(&pattern.coerce_to_string())
+                .map_err(|e| format!("Invalid regex in matches(): {}", e))?;
+            NodeValue::Boolean(re.is_match(&s.coerce_to_string()))
+        }
+        _ => return Err(format!("Unknown function '{}'", name)),
+    };
+    Ok(result)
+}
+
+pub fn contains(haystack: &NodeValue, needle: &NodeValue) -> bool {
+    match haystack {
+        NodeValue::Array(arr) => arr.iter().any(|v| values_equal(v, needle)),
+        NodeValue::Object(obj) => obj.contains_key(&needle.coerce_to_string()),
+        NodeValue::Null => false,
+        other => other
+            .coerce_to_string()
+            .contains(&needle.coerce_to_string()),
+    }
+}
+
+pub fn type_name(value: &NodeValue) -> &'static str {
+    match value {
+        NodeValue::Null => "null",
+        NodeValue::Boolean(_) => "boolean",
+        NodeValue::Integer(_) | NodeValue::Number(_) => "number",
+        NodeValue::String(_) => "string",
+        NodeValue::Array(_) => "array",
+        NodeValue::Object(_) => "object",
+        NodeValue::File { .. } => "file",
+        NodeValue::Bytes(_) => "bytes",
+        NodeValue::Stream(_) => "stream",
+    }
+}
+
+fn check_arity(name: &str, args: &[NodeValue], min: usize, max: usize) -> Result<(), String> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else {
+            format!("{} to {}", min, max)
+        };
+        return Err(format!(
+            "{}() takes {} argument(s) but {} were given",
+            name,
+            expected,
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+fn fixed_args<const N: usize>(name: &str, args: Vec<NodeValue>) -> Result<[NodeValue; N], String> {
+    check_arity(name, &args, N, N)?;
+    args.try_into()
+        .map_err(|_| format!("{}() received the wrong number of arguments", name))
+}
+
+fn single_arg(name: &str, args: Vec<NodeValue>) -> Result<NodeValue, String> {
+    let [value] = fixed_args(name, args)?;
+    Ok(value)
+}
+
+fn number_arg(name: &str, value: &NodeValue) -> Result<f64, String> {
+    match value {
+        NodeValue::Null | NodeValue::Array(_) | NodeValue::Object(_) => Err(format!(
+            "{}() expects a number, got {}",
+            name,
+            type_name(value)
+        )),
+        other => other
+            .as_number()
+            .ok_or_else(|| format!("{}() expects a number, got {}", name, type_name(other))),
+    }
+}
+
+// Whole-number results become integers when they fit
+fn integral(n: f64) -> NodeValue {
+    if n.is_finite() && n.abs() < i64::MAX as f64 {
+        NodeValue::Integer(n as i64)
+    } else {
+        NodeValue::Number(n)
+    }
+}
+
+fn map_string(
+    name: &str,
+    args: Vec<NodeValue>,
+    f: impl Fn(&str) -> String,
+) -> Result<NodeValue, String> {
+    let [value] = fixed_args(name, args)?;
+    Ok(NodeValue::String(f(&value.coerce_to_string())))
+}
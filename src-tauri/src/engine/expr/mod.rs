@@ -0,0 +1,203 @@
+mod eval;
+mod functions;
+mod parser;
+
+use crate::error::AppError;
+use crate::types::NodeValue;
+
+pub use eval::{compare_values, values_equal};
+pub use parser::Ast;
+
+// A parsed expression, reusable across items. The syntax is a small,
+// side-effect free subset of JavaScript: literals, `item.field`, `item[0]`,
+// arithmetic, comparisons, `&&`/`||`/`!`, `??`, `a ? b : c`, `x in y`,
+// array/object literals and calls to the built-in functions in `functions.rs`
+// (also usable as methods, e.g. `item.name.upper()`).
+#[derive(Debug, Clone)]
+pub struct Expression {
+    source: String,
+    ast: Ast,
+}
+
+impl Expression {
+    pub fn parse(source: &str) -> Result<Self, AppError> {
+        let ast = parser::parse(source).map_err(|e| {
+            AppError::Validation(format!(
+                "Invalid expression '{}': {} at column {}",
+                source,
+                e.message,
+                e.position + 1
+            ))
+        })?;
+        Ok(Self {
+            source: source.to_string(),
+            ast,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn evaluate(&self, vars: &[(&str, &NodeValue)]) -> Result<NodeValue, AppError> {
+        eval::evaluate(&self.ast, vars).map_err(|message| self.error(message))
+    }
+
+    // Like `evaluate`, with `name` bound to a value the expression may consume
+    pub fn evaluate_owned(
+        &self,
+        name: &str,
+        value: NodeValue,
+        vars: &[(&str, &NodeValue)],
+    ) -> Result<NodeValue, AppError> {
+        eval::evaluate_owned(&self.ast, name, value, vars).map_err(|message| self.error(message))
+    }
+
+    fn error(&self, message: String) -> AppError {
+        AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Error evaluating '{}': {}", self.source, message),
+        }
+    }
+
+    pub fn evaluate_bool(&self, vars: &[(&str, &NodeValue)]) -> Result<bool, AppError> {
+        Ok(eval::is_truthy(&self.evaluate(vars)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_with(src: &str, item: NodeValue) -> NodeValue {
+        Expression::parse(src)
+            .unwrap()
+            .evaluate(&[("item", &item)])
+            .unwrap()
+    }
+
+    fn person() -> NodeValue {
+        NodeValue::from_json(serde_json::json!({
+            "name": "Ada",
+            "age": 36,
+            "tags": ["math", "code"]
+        }))
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        let result = eval_with("1 + 2 * 3 - 4 / 2", NodeValue::Null);
+        assert_eq!(result.as_number(), Some(5.0));
+    }
+
+    #[test]
+    fn test_integer_arithmetic_is_exact() {
+        assert!(matches!(
+            eval_with("9007199254740993 + 2", NodeValue::Null),
+            NodeValue::Integer(9007199254740995)
+        ));
+        assert!(matches!(
+            eval_with("6 / 2", NodeValue::Null),
+            NodeValue::Integer(3)
+        ));
+        assert_eq!(eval_with("7 / 2", NodeValue::Null).as_number(), Some(3.5));
+        // i64::MIN / -1 does not fit an integer and widens instead of panicking
+        assert_eq!(
+            eval_with("item / -1", NodeValue::Integer(i64::MIN)).as_number(),
+            Some(-(i64::MIN as f64))
+        );
+        assert_eq!(
+            eval_with("item % -1", NodeValue::Integer(i64::MIN)).as_number(),
+            Some(0.0)
+        );
+        assert!(matches!(
+            eval_with("1 == 1.0", NodeValue::Null),
+            NodeValue::Boolean(true)
+        ));
+    }
+
+    #[test]
+    fn test_field_access_and_comparison() {
+        assert!(matches!(
+            eval_with("item.age >= 18 && item.name == 'Ada'", person()),
+            NodeValue::Boolean(true)
+        ));
+        assert_eq!(
+            eval_with("item.tags[1]", person()).as_string().unwrap(),
+            "code"
+        );
+        assert!(matches!(
+            eval_with("item.missing.deeper", person()),
+            NodeValue::Null
+        ));
+    }
+
+    #[test]
+    fn test_string_functions_and_methods() {
+        assert_eq!(
+            eval_with("upper(item.name) + '!'", person())
+                .as_string()
+                .unwrap(),
+            "ADA!"
+        );
+        assert!(matches!(
+            eval_with("item.name.lower().startsWith('ad')", person()),
+            NodeValue::Boolean(true)
+        ));
+        assert!(matches!(
+            eval_with("'math' in item.tags", person()),
+            NodeValue::Boolean(true)
+        ));
+    }
+
+    #[test]
+    fn test_ternary_and_coalesce() {
+        assert_eq!(
+            eval_with("item.age > 40 ? 'senior' : 'junior'", person())
+                .as_string()
+                .unwrap(),
+            "junior"
+        );
+        assert_eq!(
+            eval_with("item.nickname ?? item.name", person())
+                .as_string()
+                .unwrap(),
+            "Ada"
+        );
+    }
+
+    #[test]
+    fn test_null_checks() {
+        assert!(matches!(
+            eval_with("item !== null", NodeValue::Null),
+            NodeValue::Boolean(false)
+        ));
+        assert!(matches!(
+            eval_with("item != null", NodeValue::String("x".into())),
+            NodeValue::Boolean(true)
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors_report_position() {
+        let err = Expression::parse("item.age >").unwrap_err().to_string();
+        assert!(err.contains("column 11"), "{err}");
+        let err = Expression::parse("nope(1)").unwrap_err().to_string();
+        assert!(err.contains("Unknown function 'nope'"), "{err}");
+
+        let nested = format!("{}1{}", "(".repeat(60), ")".repeat(60));
+        assert_eq!(eval_with(&nested, NodeValue::Null).as_number(), Some(1.0));
+        let chained = vec!["1"; 60].join(" + ");
+        assert_eq!(eval_with(&chained, NodeValue::Null).as_number(), Some(60.0));
+        // Flat chains nest the tree as deeply as brackets do
+        for deep in [
+            format!("{}1{}", "(".repeat(5000), ")".repeat(5000)),
+            format!("{}true", "!".repeat(5000)),
+            vec!["item"; 100_000].join("+"),
+            format!("item{}", ".a".repeat(100_000)),
+        ] {
+            let err = Expression::parse(&deep).unwrap_err().to_string();
+            assert!(err.contains("nested more than 64 levels"), "{err}");
+        }
+    }
+}
@@ -0,0 +1,521 @@
+use super::functions;
+
+#[derive(Debug, Clone)]
+pub enum Ast {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Number(f64),
+    Str(String),
+    Var(String),
+    Array(Vec<Ast>),
+    Object(Vec<(String, Ast)>),
+    Member(Box<Ast>, String),
+    Index(Box<Ast>, Box<Ast>),
+    Call(String, Vec<Ast>),
+    Unary(UnaryOp, Box<Ast>),
+    Binary(BinaryOp, Box<Ast>, Box<Ast>),
+    And(Box<Ast>, Box<Ast>),
+    Or(Box<Ast>, Box<Ast>),
+    Coalesce(Box<Ast>, Box<Ast>),
+    Ternary(Box<Ast>, Box<Ast>, Box<Ast>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum UnaryOp {
+    Not,
+    Neg,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub position: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Integer(i64),
+    Number(f64),
+    Str(String),
+    Ident(String),
+    Punct(&'static str),
+    Eof,
+}
+
+// Parsing recurses for every bracket and prefix operator, and evaluating
+// recurses for every level of the tree, which a chain such as `a + b + c`
+// deepens by one per operator. All of these count towards one limit, well
+// below what would overflow a 2 MB thread stack in a debug build; the same
+// depth the script sandbox allows.
+const MAX_DEPTH: usize = 64;
+
+const PUNCTUATION: &[&str] = &[
+    "===", "!==", "==", "!=", "<=", ">=", "&&", "||", "??", "<", ">", "+", "-", "*", "/", "%", "!",
+    "?", ":", ".", ",", "(", ")", "[", "]", "{", "}",
+];
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let mut tokens = Vec::new();
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].1.is_ascii_digit() || chars[i].1 == '.') {
+                // A dot not followed by a digit is member access, e.g. `1.toString`
+                if chars[i].1 == '.' && !chars.get(i + 1).is_some_and(|(_, c)| c.is_ascii_digit()) {
+                    break;
+                }
+                i += 1;
+            }
+            let end = chars.get(i).map(|(p, _)| *p).unwrap_or(source.len());
+            let text = &source[chars[start].0..end];
+            if let Ok(n) = text.parse::<i64>() {
+                tokens.push((Token::Integer(n), pos));
+                continue;
+            }
+            let n = text.parse::<f64>().map_err(|_| ParseError {
+                message: format!("Invalid number '{}'", text),
+                position: pos,
+            })?;
+            tokens.push((Token::Number(n), pos));
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' || c == '$' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].1.is_alphanumeric() || chars[i].1 == '_' || chars[i].1 == '$')
+            {
+                i += 1;
+            }
+            let end = chars.get(i).map(|(p, _)| *p).unwrap_or(source.len());
+            tokens.push((Token::Ident(source[chars[start].0..end].to_string()), pos));
+            continue;
+        }
+
+        if c == '"' || c == '\'' {
+            let quote = c;
+            let mut value = String::new();
+            i += 1;
+            loop {
+                let Some(&(_, ch)) = chars.get(i) else {
+                    return Err(ParseError {
+                        message: "Unterminated string".to_string(),
+                        position: pos,
+                    });
+                };
+                i += 1;
+                if ch == quote {
+                    break;
+                }
+                if ch == '\\' {
+                    let Some(&(_, escaped)) = chars.get(i) else {
+                        continue;
+                    };
+                    i += 1;
+                    value.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        '0' => '\0',
+                        other => other,
+                    });
+                } else {
+                    value.push(ch);
+                }
+            }
+            tokens.push((Token::Str(value), pos));
+            continue;
+        }
+
+        let rest = &source[pos..];
+        let Some(punct) = PUNCTUATION.iter().find(|p| rest.starts_with(**p)) else {
+            return Err(ParseError {
+                message: format!("Unexpected character '{}'", c),
+                position: pos,
+            });
+        };
+        tokens.push((Token::Punct(punct), pos));
+        i += punct.chars().count();
+    }
+
+    tokens.push((Token::Eof, source.len()));
+    Ok(tokens)
+}
+
+pub fn parse(source: &str) -> Result<Ast, ParseError> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        depth: 0,
+    };
+    let ast = parser.expression()?;
+    if parser.peek() != &Token::Eof {
+        return Err(parser.unexpected());
+    }
+    Ok(ast)
+}
+
+type Rule = fn(&mut Parser) -> Result<Ast, ParseError>;
+type Combine = fn(Box<Ast>, Box<Ast>) -> Ast;
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.pos].1
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].0.clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        if matches!(self.peek(), Token::Punct(p) if *p == punct) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Token::Ident(k) if k == keyword) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<(), ParseError> {
+        if self.eat_punct(punct) {
+            Ok(())
+        } else {
+            Err(ParseError {
+                message: format!("Expected '{}' but found {}", punct, describe(self.peek())),
+                position: self.position(),
+            })
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        ParseError {
+            message: format!("Unexpected {}", describe(self.peek())),
+            position: self.position(),
+        }
+    }
+
+    fn deeper(&mut self) -> Result<(), ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError {
+                message: format!("Expression is nested more than {} levels deep", MAX_DEPTH),
+                position: self.position(),
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn nested(&mut self, parse: Rule) -> Result<Ast, ParseError> {
+        self.deeper()?;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    // Parses `operand (op operand)*` into a left-nested chain, counting each
+    // operator against the depth limit
+    fn chain(
+        &mut self,
+        operand: Rule,
+        operator: fn(&mut Self) -> Option<Combine>,
+    ) -> Result<Ast, ParseError> {
+        let start = self.depth;
+        let mut left = operand(self)?;
+        while let Some(combine) = operator(self) {
+            self.deeper()?;
+            let right = operand(self)?;
+            left = combine(Box::new(left), Box::new(right));
+        }
+        self.depth = start;
+        Ok(left)
+    }
+
+    fn expression(&mut self) -> Result<Ast, ParseError> {
+        self.nested(Self::ternary)
+    }
+
+    fn ternary(&mut self) -> Result<Ast, ParseError> {
+        let condition = self.coalesce()?;
+        if self.eat_punct("?") {
+            let then = self.expression()?;
+            self.expect_punct(":")?;
+            let otherwise = self.expression()?;
+            return Ok(Ast::Ternary(
+                Box::new(condition),
+                Box::new(then),
+                Box::new(otherwise),
+            ));
+        }
+        Ok(condition)
+    }
+
+    fn coalesce(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::or, |p| p.eat_punct("??").then_some(Ast::Coalesce))
+    }
+
+    fn or(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::and, |p| {
+            (p.eat_punct("||") || p.eat_keyword("or")).then_some(Ast::Or)
+        })
+    }
+
+    fn and(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::equality, |p| {
+            (p.eat_punct("&&") || p.eat_keyword("and")).then_some(Ast::And)
+        })
+    }
+
+    fn equality(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::comparison, |p| {
+            if p.eat_punct("===") || p.eat_punct("==") {
+                Some(|l, r| Ast::Binary(BinaryOp::Eq, l, r))
+            } else if p.eat_punct("!==") || p.eat_punct("!=") {
+                Some(|l, r| Ast::Binary(BinaryOp::Ne, l, r))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn comparison(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::additive, |p| {
+            if p.eat_punct("<=") {
+                Some(|l, r| Ast::Binary(BinaryOp::Le, l, r))
+            } else if p.eat_punct(">=") {
+                Some(|l, r| Ast::Binary(BinaryOp::Ge, l, r))
+            } else if p.eat_punct("<") {
+                Some(|l, r| Ast::Binary(BinaryOp::Lt, l, r))
+            } else if p.eat_punct(">") {
+                Some(|l, r| Ast::Binary(BinaryOp::Gt, l, r))
+            } else if p.eat_keyword("in") {
+                Some(|l, r| Ast::Binary(BinaryOp::In, l, r))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn additive(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::multiplicative, |p| {
+            if p.eat_punct("+") {
+                Some(|l, r| Ast::Binary(BinaryOp::Add, l, r))
+            } else if p.eat_punct("-") {
+                Some(|l, r| Ast::Binary(BinaryOp::Sub, l, r))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn multiplicative(&mut self) -> Result<Ast, ParseError> {
+        self.chain(Self::unary, |p| {
+            if p.eat_punct("*") {
+                Some(|l, r| Ast::Binary(BinaryOp::Mul, l, r))
+            } else if p.eat_punct("/") {
+                Some(|l, r| Ast::Binary(BinaryOp::Div, l, r))
+            } else if p.eat_punct("%") {
+                Some(|l, r| Ast::Binary(BinaryOp::Rem, l, r))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn unary(&mut self) -> Result<Ast, ParseError> {
+        if self.eat_punct("!") || self.eat_keyword("not") {
+            let operand = self.nested(Self::unary)?;
+            return Ok(Ast::Unary(UnaryOp::Not, Box::new(operand)));
+        }
+        if self.eat_punct("-") {
+            let operand = self.nested(Self::unary)?;
+            return Ok(Ast::Unary(UnaryOp::Neg, Box::new(operand)));
+        }
+        self.postfix()
+    }
+
+    // Member access, indexing and method calls nest like chained operators
+    fn postfix(&mut self) -> Result<Ast, ParseError> {
+        let start = self.depth;
+        let mut expr = self.primary()?;
+        loop {
+            if matches!(self.peek(), Token::Punct("." | "[")) {
+                self.deeper()?;
+            }
+            if self.eat_punct(".") {
+                let position = self.position();
+                let Token::Ident(name) = self.advance() else {
+                    return Err(ParseError {
+                        message: "Expected field name after '.'".to_string(),
+                        position,
+                    });
+                };
+                if matches!(self.peek(), Token::Punct("(")) {
+                    // Method syntax: `x.f(a)` is sugar for `f(x, a)`
+                    let mut args = vec![expr];
+                    args.extend(self.call_args(&name, position)?);
+                    expr = Ast::Call(name, args);
+                } else {
+                    expr = Ast::Member(Box::new(expr), name);
+                }
+            } else if self.eat_punct("[") {
+                let index = self.expression()?;
+                self.expect_punct("]")?;
+                expr = Ast::Index(Box::new(expr), Box::new(index));
+            } else {
+                self.depth = start;
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn call_args(&mut self, name: &str, position: usize) -> Result<Vec<Ast>, ParseError> {
+        if !functions::exists(name) {
+            return Err(ParseError {
+                message: format!("Unknown function '{}'", name),
+                position,
+            });
+        }
+        self.expect_punct("(")?;
+        let mut args = Vec::new();
+        if !self.eat_punct(")") {
+            loop {
+                args.push(self.expression()?);
+                if self.eat_punct(")") {
+                    break;
+                }
+                self.expect_punct(",")?;
+            }
+        }
+        Ok(args)
+    }
+
+    fn primary(&mut self) -> Result<Ast, ParseError> {
+        let position = self.position();
+        match self.advance() {
+            Token::Integer(n) => Ok(Ast::Integer(n)),
+            Token::Number(n) => Ok(Ast::Number(n)),
+            Token::Str(s) => Ok(Ast::Str(s)),
+            Token::Ident(name) => match name.as_str() {
+                "true" => Ok(Ast::Bool(true)),
+                "false" => Ok(Ast::Bool(false)),
+                "null" | "undefined" => Ok(Ast::Null),
+                _ if matches!(self.peek(), Token::Punct("(")) => {
+                    let args = self.call_args(&name, position)?;
+                    Ok(Ast::Call(name, args))
+                }
+                _ => Ok(Ast::Var(name)),
+            },
+            Token::Punct("(") => {
+                let expr = self.expression()?;
+                self.expect_punct(")")?;
+                Ok(expr)
+            }
+            Token::Punct("[") => {
+                let mut items = Vec::new();
+                if !self.eat_punct("]") {
+                    loop {
+                        items.push(self.expression()?);
+                        if self.eat_punct("]") {
+                            break;
+                        }
+                        self.expect_punct(",")?;
+                    }
+                }
+                Ok(Ast::Array(items))
+            }
+            Token::Punct("{") => {
+                let mut fields = Vec::new();
+                if !self.eat_punct("}") {
+                    loop {
+                        let key_position = self.position();
+                        let key = match self.advance() {
+                            Token::Ident(k) | Token::Str(k) => k,
+                            other => {
+                                return Err(ParseError {
+                                    message: format!(
+                                        "Expected object key but found {}",
+                                        describe(&other)
+                                    ),
+                                    position: key_position,
+                                })
+                            }
+                        };
+                        self.expect_punct(":")?;
+                        fields.push((key, self.expression()?));
+                        if self.eat_punct("}") {
+                            break;
+                        }
+                        self.expect_punct(",")?;
+                    }
+                }
+                Ok(Ast::Object(fields))
+            }
+            other => Err(ParseError {
+                message: format!("Unexpected {}", describe(&other)),
+                position,
+            }),
+        }
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Integer(n) => format!("number {}", n),
+        Token::Number(n) => format!("number {}", n),
+        Token::Str(s) => format!("string '{}'", s),
+        Token::Ident(name) => format!("'{}'", name),
+        Token::Punct(p) => format!("'{}'", p),
+        Token::Eof => "end of expression".to_string(),
+    }
+}
@@ -1,5 +1,6 @@
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::algo::toposort;
+use petgraph::visit::EdgeRef;
 use std::collections::HashMap;
 
 use crate::error::AppError;
@@ -53,14 +54,31 @@ impl FlowGraph {
         })
     }
 
+    // Number of outgoing edges per node, i.e. how many times its outputs are read
+    pub fn consumer_counts(&self) -> HashMap<String, usize> {
+        self.node_indices
+            .iter()
+            .map(|(id, idx)| {
+                let count = self
+                    .graph
+                    .edges_directed(*idx, petgraph::Direction::Outgoing)
+                    .count();
+                (id.clone(), count)
+            })
+            .collect()
+    }
+
     pub fn get_input_edges(&self, node_id: &str) -> Vec<&FlowEdge> {
         let Some(idx) = self.node_indices.get(node_id) else {
             return vec![];
         };
-        self.graph
+        // Edge indices follow the document's edge order
+        let mut edges: Vec<_> = self
+            .graph
             .edges_directed(*idx, petgraph::Direction::Incoming)
-            .map(|e| e.weight())
-            .collect()
+            .collect();
+        edges.sort_by_key(|e| e.id());
+        edges.into_iter().map(|e| e.weight()).collect()
     }
 }
 
@@ -156,4 +174,20 @@ mod tests {
         assert_eq!(graph.execution_layers[0].len(), 2); // a and b
         assert_eq!(graph.execution_layers[1].len(), 1); // c
     }
+
+    #[test]
+    fn test_input_edges_follow_document_order() {
+        let doc = make_doc(
+            vec![("a", "textInput"), ("b", "textInput"), ("c", "merge")],
+            vec![("b", "c"), ("a", "c")],
+        );
+        let graph = FlowGraph::from_document(&doc).unwrap();
+        let sources: Vec<_> = graph
+            .get_input_edges("c")
+            .iter()
+            .map(|e| e.source.as_str())
+            .collect();
+        assert_eq!(sources, vec!["b", "a"]);
+        assert_eq!(graph.consumer_counts()["a"], 1);
+    }
 }
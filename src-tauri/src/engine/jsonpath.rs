@@ -0,0 +1,523 @@
+use super::expr::Expression;
+use crate::error::AppError;
+use crate::types::NodeValue;
+
+// A compiled JSONPath query over `NodeValue`s. Supported syntax:
+//
+//   $                 the root (optional: `data.items` means `$.data.items`)
+//   .name, ['name']   object member
+//   [0], [-1]         array element, negative counts from the end
+//   .*, [*]           every member or element
+//   [1:5:2]           array slice (start:end:step)
+//   [0,2], ['a','b']  union of indices or names
+//   ..name, ..*       recursive descent
+//   [?(@.price < 10)] filter, using the expression language with `@` as the
+//                     current value and `$` as the root
+#[derive(Debug, Clone)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone)]
+struct Segment {
+    descendants: bool,
+    selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+    Slice(Option<i64>, Option<i64>, i64),
+    Filter(Expression),
+}
+
+struct ParseError {
+    message: String,
+    position: usize,
+}
+
+impl JsonPath {
+    pub fn parse(source: &str) -> Result<Self, AppError> {
+        let segments = Parser::new(source).parse().map_err(|e| {
+            let near: String = source[e.position..].chars().take(20).collect();
+            let near = if near.is_empty() {
+                "end of query".to_string()
+            } else {
+                format!("'{}'", near)
+            };
+            AppError::Validation(format!(
+                "Invalid query '{}': {} at column {} (near {})",
+                source,
+                e.message,
+                source[..e.position].chars().count() + 1,
+                near
+            ))
+        })?;
+        Ok(Self { segments })
+    }
+
+    // A definite path names at most one value; anything with wildcards,
+    // slices, unions, filters or descent may match several
+    pub fn is_definite(&self) -> bool {
+        self.segments.iter().all(|segment| {
+            !segment.descendants
+                && segment.selectors.len() == 1
+                && matches!(segment.selectors[0], Selector::Name(_) | Selector::Index(_))
+        })
+    }
+
+    // The member names along a path made only of names, such as
+    // `address.city` or `$['first name']`, for nodes that write to a field;
+    // None for any other path
+    pub fn member_names(&self) -> Option<Vec<&str>> {
+        self.segments
+            .iter()
+            .map(|segment| match segment.selectors.as_slice() {
+                [Selector::Name(name)] if !segment.descendants => Some(name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn select<'a>(&self, root: &'a NodeValue) -> Result<Vec<&'a NodeValue>, AppError> {
+        let mut current = vec![root];
+        for segment in &self.segments {
+            let candidates = if segment.descendants {
+                let mut all = Vec::new();
+                for value in current {
+                    collect_descendants(value, &mut all);
+                }
+                all
+            } else {
+                current
+            };
+            let mut next = Vec::new();
+            for value in candidates {
+                for selector in &segment.selectors {
+                    self.apply(selector, value, root, &mut next)?;
+                }
+            }
+            current = next;
+        }
+        Ok(current)
+    }
+
+    fn apply<'a>(
+        &self,
+        selector: &Selector,
+        value: &'a NodeValue,
+        root: &'a NodeValue,
+        out: &mut Vec<&'a NodeValue>,
+    ) -> Result<(), AppError> {
+        match (selector, value) {
+            (Selector::Name(name), NodeValue::Object(obj)) => out.extend(obj.get(name)),
+            (Selector::Index(i), NodeValue::Array(items)) => {
+                out.extend(resolve_index(*i, items.len()).map(|i| &items[i]))
+            }
+            (Selector::Wildcard, _) => out.extend(children(value)),
+            (Selector::Slice(start, end, step), NodeValue::Array(items)) => {
+                out.extend(slice_indices(*start, *end, *step, items.len()).map(|i| &items[i]))
+            }
+            (Selector::Filter(expr), _) => {
+                for child in children(value) {
+                    if expr.evaluate_bool(&[("item", child), ("$", root)])? {
+                        out.push(child);
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn children(value: &NodeValue) -> Vec<&NodeValue> {
+    match value {
+        NodeValue::Array(items) => items.iter().collect(),
+        NodeValue::Object(obj) => obj.values().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn collect_descendants<'a>(value: &'a NodeValue, out: &mut Vec<&'a NodeValue>) {
+    out.push(value);
+    for child in children(value) {
+        collect_descendants(child, out);
+    }
+}
+
+fn resolve_index(i: i64, len: usize) -> Option<usize> {
+    let i = if i < 0 { len as i64 + i } else { i };
+    (0..len as i64).contains(&i).then_some(i as usize)
+}
+
+// Python-style slice bounds, clamped to the array
+fn slice_indices(
+    start: Option<i64>,
+    end: Option<i64>,
+    step: i64,
+    len: usize,
+) -> impl Iterator<Item = usize> {
+    let len = len as i64;
+    let clamp = |i: i64, low: i64, high: i64| {
+        let i = if i < 0 { len + i } else { i };
+        i.clamp(low, high)
+    };
+    let indices: Vec<usize> = if step > 0 {
+        let start = start.map_or(0, |s| clamp(s, 0, len));
+        let end = end.map_or(len, |e| clamp(e, 0, len));
+        (start..end)
+            .step_by(step as usize)
+            .map(|i| i as usize)
+            .collect()
+    } else {
+        let start = start.map_or(len - 1, |s| clamp(s, -1, len - 1));
+        let end = end.map_or(-1, |e| clamp(e, -1, len - 1));
+        let mut indices = Vec::new();
+        let mut i = start;
+        while i > end {
+            indices.push(i as usize);
+            i += step;
+        }
+        indices
+    };
+    indices.into_iter()
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Vec<Segment>, ParseError> {
+        self.skip_whitespace();
+        let mut segments = Vec::new();
+        // Without `$` the query may start with a bare name, as in `data.items[*]`
+        if !self.eat('$') && self.peek().is_some_and(is_name_char) {
+            let name = self.name()?;
+            segments.push(Segment::single(false, Selector::Name(name)));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let Some(c) = self.peek() else {
+                return Ok(segments);
+            };
+            match c {
+                '.' => {
+                    self.pos += 1;
+                    let descendants = self.eat('.');
+                    if descendants && self.peek() == Some('[') {
+                        let selectors = self.bracket()?;
+                        segments.push(Segment {
+                            descendants,
+                            selectors,
+                        });
+                    } else if self.eat('*') {
+                        segments.push(Segment::single(descendants, Selector::Wildcard));
+                    } else {
+                        let name = self.name()?;
+                        segments.push(Segment::single(descendants, Selector::Name(name)));
+                    }
+                }
+                '[' => {
+                    let selectors = self.bracket()?;
+                    segments.push(Segment {
+                        descendants: false,
+                        selectors,
+                    });
+                }
+                _ => return Err(self.error(format!("Unexpected '{}'", c))),
+            }
+        }
+    }
+
+    fn bracket(&mut self) -> Result<Vec<Selector>, ParseError> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        if self.eat('?') {
+            let filter = self.filter()?;
+            self.expect(']')?;
+            return Ok(vec![filter]);
+        }
+
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.selector()?);
+            self.skip_whitespace();
+            if self.eat(']') {
+                return Ok(selectors);
+            }
+            if !self.eat(',') {
+                return Err(self.error("Expected ',' or ']'".to_string()));
+            }
+        }
+    }
+
+    fn selector(&mut self) -> Result<Selector, ParseError> {
+        match self.peek() {
+            Some('*') => {
+                self.pos += 1;
+                Ok(Selector::Wildcard)
+            }
+            Some(quote @ ('\'' | '"')) => Ok(Selector::Name(self.string(quote)?)),
+            Some(c) if c == '-' || c == ':' || c.is_ascii_digit() => {
+                let start = self.integer()?;
+                self.skip_whitespace();
+                if !self.eat(':') {
+                    return start
+                        .map(Selector::Index)
+                        .ok_or_else(|| self.error("Expected an index".to_string()));
+                }
+                self.skip_whitespace();
+                let end = self.integer()?;
+                self.skip_whitespace();
+                let step_pos = self.pos;
+                let step = if self.eat(':') {
+                    self.skip_whitespace();
+                    self.integer()?.unwrap_or(1)
+                } else {
+                    1
+                };
+                if step == 0 {
+                    return Err(ParseError {
+                        message: "Slice step cannot be zero".to_string(),
+                        position: step_pos,
+                    });
+                }
+                Ok(Selector::Slice(start, end, step))
+            }
+            Some(c) => Err(self.error(format!("Unexpected '{}' in brackets", c))),
+            None => Err(self.error("Unterminated '['".to_string())),
+        }
+    }
+
+    // The filter runs until the `]` that closes its bracket, skipping over
+    // nested brackets, parentheses and string literals
+    fn filter(&mut self) -> Result<Selector, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut depth = 0;
+        let mut quote = None;
+        let mut expression = String::new();
+        while let Some(c) = self.peek() {
+            match (quote, c) {
+                (Some(q), _) if c == q => quote = None,
+                (Some(_), '\\') => {
+                    expression.push(c);
+                    self.pos += 1;
+                    let Some(escaped) = self.peek() else { break };
+                    expression.push(escaped);
+                    self.pos += escaped.len_utf8();
+                    continue;
+                }
+                (Some(_), _) => {}
+                (None, '\'' | '"') => quote = Some(c),
+                (None, '[' | '(') => depth += 1,
+                (None, ']') if depth == 0 => break,
+                (None, ']' | ')') => depth -= 1,
+                _ => {}
+            }
+            // `@` is the current value, which the expression language calls `item`
+            if quote.is_none() && c == '@' {
+                expression.push_str("item");
+            } else {
+                expression.push(c);
+            }
+            self.pos += c.len_utf8();
+        }
+
+        if self.peek().is_none() {
+            return Err(self.error("Unterminated filter".to_string()));
+        }
+        if expression.trim().is_empty() {
+            return Err(ParseError {
+                message: "Empty filter".to_string(),
+                position: start,
+            });
+        }
+        Expression::parse(expression.trim())
+            .map(Selector::Filter)
+            .map_err(|e| ParseError {
+                message: match e {
+                    AppError::Validation(message) => format!("Invalid filter: {}", message),
+                    other => other.to_string(),
+                },
+                position: start,
+            })
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.pos += self.peek().map_or(0, char::len_utf8);
+        }
+        if self.pos == start {
+            return Err(self.error("Expected a member name".to_string()));
+        }
+        Ok(self.source[start..self.pos].to_string())
+    }
+
+    fn string(&mut self, quote: char) -> Result<String, ParseError> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+            if c == quote {
+                return Ok(value);
+            }
+            if c == '\\' {
+                if let Some(escaped) = self.peek() {
+                    self.pos += escaped.len_utf8();
+                    value.push(escaped);
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        Err(ParseError {
+            message: "Unterminated string".to_string(),
+            position: start,
+        })
+    }
+
+    fn integer(&mut self) -> Result<Option<i64>, ParseError> {
+        let start = self.pos;
+        self.eat('-');
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let text = &self.source[start..self.pos];
+        if text.is_empty() {
+            return Ok(None);
+        }
+        text.parse().map(Some).map_err(|_| ParseError {
+            message: format!("Invalid index '{}'", text),
+            position: start,
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected '{}'", c)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        ParseError {
+            message,
+            position: self.pos,
+        }
+    }
+}
+
+impl Segment {
+    fn single(descendants: bool, selector: Selector) -> Self {
+        Self {
+            descendants,
+            selectors: vec![selector],
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '$'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> NodeValue {
+        NodeValue::from_json(serde_json::json!({
+            "store": {
+                "books": [
+                    { "title": "Dune", "price": 9, "tags": ["scifi"] },
+                    { "title": "Emma", "price": 12 },
+                    { "title": "Ulysses", "price": 15, "isbn": "0-553" }
+                ],
+                "bike": { "price": 200 }
+            }
+        }))
+    }
+
+    fn query(path: &str) -> Vec<serde_json::Value> {
+        let root = store();
+        JsonPath::parse(path)
+            .unwrap()
+            .select(&root)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.to_json_value())
+            .collect()
+    }
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(query("store.books[*].title"), ["Dune", "Emma", "Ulysses"]);
+        assert_eq!(query("$['store']['books'][-1].price"), [15]);
+        assert_eq!(query("$.store.books[0,2].title"), ["Dune", "Ulysses"]);
+        assert_eq!(query("$.store.books[::-2].title"), ["Ulysses", "Dune"]);
+        assert_eq!(query("$..price"), [9, 12, 15, 200]);
+        assert_eq!(
+            query("$.store.books[?(@.price > 10 && @.isbn)].title"),
+            ["Ulysses"]
+        );
+        assert_eq!(
+            query("$.store.books[?@.price < $.store.bike.price / 20].title"),
+            ["Dune"]
+        );
+        assert!(query("$.store.missing[0]").is_empty());
+        // Non-ASCII spaces are skipped like any other whitespace
+        assert_eq!(
+            query("$['store'][\u{a0}'books'\u{3000}][0].title"),
+            ["Dune"]
+        );
+        assert!(JsonPath::parse("store.books[0].title")
+            .unwrap()
+            .is_definite());
+        assert!(!JsonPath::parse("store.books[*]").unwrap().is_definite());
+    }
+
+    #[test]
+    fn test_errors_point_at_segment() {
+        let err = JsonPath::parse("$.store.books[0.title").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Invalid query '$.store.books[0.title': Expected ',' or ']' at column 16 (near '.title')"
+        );
+        let err = JsonPath::parse("$.books[?(@.price <)]").unwrap_err();
+        assert!(err.to_string().contains("Invalid filter"));
+        assert!(err.to_string().contains("column 10"));
+    }
+}
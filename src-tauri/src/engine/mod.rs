@@ -1,6 +1,11 @@
 pub mod context;
+pub mod encoding;
 pub mod executor;
+pub mod expr;
 pub mod graph;
+pub mod jsonpath;
+pub mod paths;
+pub mod stream;
 pub mod value;
 
 pub use executor::Engine;
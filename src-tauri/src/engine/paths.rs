@@ -0,0 +1,117 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::fs::Metadata;
+use std::path::{Component, Path};
+
+use crate::error::AppError;
+use crate::types::NodeValue;
+
+// A path a file node works on: the input of that name when connected,
+// otherwise the setting. A File value (from File Read, File Write or
+// Directory List) stands for its path.
+pub fn path_setting(
+    inputs: &HashMap<String, NodeValue>,
+    config: &serde_json::Value,
+    key: &str,
+) -> String {
+    match inputs.get(key) {
+        Some(NodeValue::File { path }) => path.clone(),
+        Some(NodeValue::Null) | None => config
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        Some(value) => value.coerce_to_string(),
+    }
+}
+
+// Every path a node reads, writes or lists goes through this check. `..`
+// segments are refused so a path built from data (a file name from an API
+// response or an archive entry) cannot climb out of the intended directory;
+// names that merely contain dots, such as `report..v2.csv`, are fine.
+pub fn check_path(path: &str) -> Result<&Path, AppError> {
+    if path.trim().is_empty() {
+        return Err(path_error("No file path provided".to_string()));
+    }
+    let checked = Path::new(path);
+    if checked
+        .components()
+        .any(|component| component == Component::ParentDir)
+    {
+        return Err(path_error(format!(
+            "Path traversal not allowed: '{}'",
+            path
+        )));
+    }
+    Ok(checked)
+}
+
+// What Directory List and File Ops report about a file or folder. The
+// modification time follows the RFC 3339 convention of the Date & Time node.
+pub fn describe_file(path: &Path, metadata: &Metadata) -> IndexMap<String, NodeValue> {
+    let is_dir = metadata.is_dir();
+    let text = |part: Option<&std::ffi::OsStr>| {
+        NodeValue::String(
+            part.map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        )
+    };
+    let modified = metadata
+        .modified()
+        .map(|time| {
+            NodeValue::String(
+                DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Secs, true),
+            )
+        })
+        .unwrap_or_default();
+
+    let full = path.to_string_lossy().into_owned();
+    let mut described = IndexMap::new();
+    described.insert("file".to_string(), NodeValue::File { path: full.clone() });
+    described.insert("path".to_string(), NodeValue::String(full));
+    described.insert("name".to_string(), text(path.file_name()));
+    described.insert(
+        "extension".to_string(),
+        text(path.extension().filter(|_| !is_dir)),
+    );
+    described.insert(
+        "size".to_string(),
+        NodeValue::Integer(if is_dir { 0 } else { metadata.len() as i64 }),
+    );
+    described.insert("modified".to_string(), modified);
+    described.insert("isDir".to_string(), NodeValue::Boolean(is_dir));
+    described
+}
+
+fn path_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_path() {
+        assert!(check_path("/data/exports/report..v2.csv").is_ok());
+        assert!(check_path("exports/2024/*.csv").is_ok());
+        for path in ["", "  ", "../secrets.txt", "/data/../etc/passwd", "a/b/.."] {
+            assert!(check_path(path).is_err(), "{:?}", path);
+        }
+
+        let mut inputs = HashMap::new();
+        let config = serde_json::json!({ "path": "/tmp/fallback.txt" });
+        assert_eq!(path_setting(&inputs, &config, "path"), "/tmp/fallback.txt");
+        inputs.insert(
+            "path".to_string(),
+            NodeValue::File {
+                path: "/tmp/listed.csv".to_string(),
+            },
+        );
+        assert_eq!(path_setting(&inputs, &config, "path"), "/tmp/listed.csv");
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::io::AsyncReadExt;
+
+use super::encoding::TextDecoder;
+use crate::error::AppError;
+use crate::types::NodeValue;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Applied to each record of a stream; returning `None` drops the record
+pub type RecordFn = dyn Fn(NodeValue, usize) -> Result<Option<NodeValue>, AppError> + Send + Sync;
+
+// A lazily evaluated sequence of values. A stream is only a description of
+// where the data comes from and which operations apply to it: every consumer
+// opens its own reader, so a stream can be cloned and stored freely without
+// holding its contents in memory.
+//
+// A stream starts out as text (chunks of a file); `split` turns it into a
+// stream of records, which `map_records` can then filter or transform.
+#[derive(Clone)]
+pub struct ValueStream {
+    source: Arc<StreamSource>,
+    ops: Vec<StreamOp>,
+}
+
+enum StreamSource {
+    File { path: PathBuf, encoding: String },
+}
+
+#[derive(Clone)]
+enum StreamOp {
+    Split(String),
+    Records(&'static str, Arc<RecordFn>),
+}
+
+impl ValueStream {
+    pub fn from_file(path: impl Into<PathBuf>, encoding: &str) -> Self {
+        Self {
+            source: Arc::new(StreamSource::File {
+                path: path.into(),
+                encoding: encoding.to_string(),
+            }),
+            ops: Vec::new(),
+        }
+    }
+
+    // Text streams yield string chunks of arbitrary size; record streams
+    // yield one value per record
+    pub fn is_text(&self) -> bool {
+        !self.ops.iter().any(|op| matches!(op, StreamOp::Split(_)))
+    }
+
+    // Splits a text stream into trimmed records, like the Split node does
+    // for a whole string
+    pub fn split(&self, delimiter: &str) -> Self {
+        self.with_op(StreamOp::Split(delimiter.to_string()))
+    }
+
+    pub fn map_records(
+        &self,
+        label: &'static str,
+        f: impl Fn(NodeValue, usize) -> Result<Option<NodeValue>, AppError> + Send + Sync + 'static,
+    ) -> Self {
+        self.with_op(StreamOp::Records(label, Arc::new(f)))
+    }
+
+    fn with_op(&self, op: StreamOp) -> Self {
+        let mut ops = self.ops.clone();
+        ops.push(op);
+        Self {
+            source: self.source.clone(),
+            ops,
+        }
+    }
+
+    pub async fn open(&self) -> Result<StreamReader, AppError> {
+        let StreamSource::File { path, encoding } = self.source.as_ref();
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| stream_error(format!("Failed to open '{}': {}", path.display(), e)))?;
+        Ok(StreamReader {
+            file: Some(file),
+            encoding: encoding.clone(),
+            decoder: None,
+            buf: vec![0; CHUNK_SIZE],
+            stages: self
+                .ops
+                .iter()
+                .map(|op| Stage {
+                    op: op.clone(),
+                    buffer: String::new(),
+                    index: 0,
+                })
+                .collect(),
+            pending: VecDeque::new(),
+        })
+    }
+
+    // Reads the whole stream into a string (text) or an array (records), for
+    // nodes that work on complete values
+    pub async fn materialize(&self) -> Result<NodeValue, AppError> {
+        let mut reader = self.open().await?;
+        if self.is_text() {
+            let mut text = String::new();
+            while let Some(chunk) = reader.next().await? {
+                text.push_str(&chunk.coerce_to_string());
+            }
+            Ok(NodeValue::String(text))
+        } else {
+            let mut records = Vec::new();
+            while let Some(record) = reader.next().await? {
+                records.push(record);
+            }
+            Ok(NodeValue::Array(records))
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let StreamSource::File { path, .. } = self.source.as_ref();
+        let mut description = format!("Stream: {}", path.display());
+        for op in &self.ops {
+            match op {
+                StreamOp::Split(delimiter) => {
+                    description.push_str(&format!(" | split {:?}", delimiter))
+                }
+                StreamOp::Records(label, _) => description.push_str(&format!(" | {}", label)),
+            }
+        }
+        description
+    }
+}
+
+impl fmt::Debug for ValueStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+pub struct StreamReader {
+    file: Option<tokio::fs::File>,
+    encoding: String,
+    decoder: Option<TextDecoder>,
+    buf: Vec<u8>,
+    stages: Vec<Stage>,
+    pending: VecDeque<NodeValue>,
+}
+
+struct Stage {
+    op: StreamOp,
+    buffer: String,
+    index: usize,
+}
+
+impl StreamReader {
+    pub async fn next(&mut self) -> Result<Option<NodeValue>, AppError> {
+        loop {
+            if let Some(value) = self.pending.pop_front() {
+                return Ok(Some(value));
+            }
+            let Some(file) = self.file.as_mut() else {
+                return Ok(None);
+            };
+
+            let n = file
+                .read(&mut self.buf)
+                .await
+                .map_err(|e| stream_error(format!("Failed to read stream: {}", e)))?;
+            let last = n == 0;
+            let bytes = &self.buf[..n];
+            let decoder = match &mut self.decoder {
+                Some(decoder) => decoder,
+                None => self
+                    .decoder
+                    .insert(TextDecoder::new(&self.encoding, bytes).map_err(stream_error)?),
+            };
+            let text = decoder.decode_chunk(bytes, last).map_err(stream_error)?;
+            if last {
+                self.file = None;
+            }
+
+            let mut values = if text.is_empty() {
+                Vec::new()
+            } else {
+                vec![NodeValue::String(text)]
+            };
+            for stage in &mut self.stages {
+                values = stage.process(values, last)?;
+            }
+            self.pending.extend(values);
+        }
+    }
+}
+
+impl Stage {
+    fn process(&mut self, values: Vec<NodeValue>, last: bool) -> Result<Vec<NodeValue>, AppError> {
+        match &self.op {
+            StreamOp::Split(delimiter) => {
+                for value in values {
+                    self.buffer.push_str(&value.coerce_to_string());
+                }
+                let mut records = Vec::new();
+                let mut start = 0;
+                while let Some(pos) = self.buffer[start..].find(delimiter.as_str()) {
+                    records.push(record(&self.buffer[start..start + pos]));
+                    start += pos + delimiter.len();
+                }
+                self.buffer.drain(..start);
+                if last {
+                    records.push(record(&self.buffer));
+                    self.buffer.clear();
+                }
+                Ok(records)
+            }
+            StreamOp::Records(_, f) => {
+                let mut out = Vec::with_capacity(values.len());
+                for value in values {
+                    let index = self.index;
+                    self.index += 1;
+                    if let Some(value) = f(value, index)? {
+                        out.push(value);
+                    }
+                }
+                Ok(out)
+            }
+        }
+    }
+}
+
+fn record(s: &str) -> NodeValue {
+    NodeValue::String(s.trim().to_string())
+}
+
+fn stream_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_split_records_across_chunks() {
+        let path =
+            std::env::temp_dir().join(format!("signalflow_stream_{}.log", std::process::id()));
+        let lines: Vec<String> = (0..20_000).map(|i| format!("line {}", i)).collect();
+        tokio::fs::write(&path, lines.join("\n")).await.unwrap();
+
+        let stream = ValueStream::from_file(&path, "utf-8")
+            .split("\n")
+            .map_records("filter", |item, index| Ok((index % 2 == 0).then_some(item)));
+        assert!(!stream.is_text());
+
+        let NodeValue::Array(records) = stream.materialize().await.unwrap() else {
+            panic!("Expected array");
+        };
+        assert_eq!(records.len(), 10_000);
+        assert_eq!(records[1].as_string().unwrap(), "line 2");
+        assert_eq!(records[9_999].as_string().unwrap(), "line 19998");
+
+        // Each consumer reads independently
+        let text = ValueStream::from_file(&path, "utf-8")
+            .materialize()
+            .await
+            .unwrap();
+        assert_eq!(text.as_string().unwrap(), lines.join("\n"));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
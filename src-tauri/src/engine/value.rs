@@ -1,9 +1,14 @@
-use crate::types::NodeValue;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use indexmap::IndexMap;
+
+use crate::types::{NodeValue, TYPE_KEY};
 
 impl NodeValue {
     pub fn coerce_to_string(&self) -> String {
         match self {
             NodeValue::String(s) => s.clone(),
+            NodeValue::Integer(i) => i.to_string(),
             NodeValue::Number(n) => n.to_string(),
             NodeValue::Boolean(b) => b.to_string(),
             NodeValue::Null => String::new(),
@@ -11,10 +16,130 @@ impl NodeValue {
                 &arr.iter().map(|v| v.to_json_value()).collect::<Vec<_>>(),
             )
             .unwrap_or_default(),
-            NodeValue::Object(obj) => {
-                serde_json::to_string(obj).unwrap_or_default()
+            NodeValue::Object(_) => {
+                serde_json::to_string(&self.to_json_value()).unwrap_or_default()
             }
             NodeValue::File { path } => path.clone(),
+            NodeValue::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+            // Streams are materialized before reaching nodes that need their text
+            NodeValue::Stream(stream) => stream.describe(),
+        }
+    }
+
+    // Raw bytes for binary sinks; non-binary values use their string form
+    pub fn coerce_to_bytes(&self) -> Vec<u8> {
+        match self {
+            NodeValue::Bytes(bytes) => bytes.clone(),
+            other => other.coerce_to_string().into_bytes(),
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            NodeValue::Integer(i) => Some(*i),
+            NodeValue::Number(n) if n.fract() == 0.0 && n.abs() < i64::MAX as f64 => {
+                Some(*n as i64)
+            }
+            NodeValue::String(s) => s.trim().parse().ok(),
+            NodeValue::Boolean(b) => Some(*b as i64),
+            _ => None,
+        }
+    }
+
+    // Parses a numeric string, keeping integers exact
+    pub fn parse_number(s: &str) -> Option<NodeValue> {
+        let s = s.trim();
+        if let Ok(i) = s.parse::<i64>() {
+            return Some(NodeValue::Integer(i));
+        }
+        s.parse::<f64>().ok().map(NodeValue::Number)
+    }
+
+    // Best-effort typing of text fields (CSV cells, XML text): empty is null,
+    // then booleans and numbers. Numbers with leading zeros such as zip codes
+    // or IDs stay strings.
+    pub fn infer_from_str(s: &str) -> NodeValue {
+        let trimmed = s.trim();
+        match trimmed {
+            "" => return NodeValue::Null,
+            "true" | "TRUE" | "True" => return NodeValue::Boolean(true),
+            "false" | "FALSE" | "False" => return NodeValue::Boolean(false),
+            _ => {}
+        }
+        let digits = trimmed.trim_start_matches(['-', '+']);
+        let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+        let numeric = trimmed
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'));
+        if numeric && !leading_zero {
+            if let Some(n) = NodeValue::parse_number(trimmed) {
+                return n;
+            }
+        }
+        NodeValue::String(s.to_string())
+    }
+
+    pub fn from_json(v: serde_json::Value) -> NodeValue {
+        match v {
+            serde_json::Value::Null => NodeValue::Null,
+            serde_json::Value::Bool(b) => NodeValue::Boolean(b),
+            serde_json::Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+                (Some(i), _) => NodeValue::Integer(i),
+                // Integers above the i64 range, such as 64-bit IDs, are kept
+                // exact as text rather than rounded to a float
+                (None, Some(u)) => NodeValue::String(u.to_string()),
+                _ => NodeValue::Number(n.as_f64().unwrap_or(0.0)),
+            },
+            serde_json::Value::String(s) => NodeValue::String(s),
+            serde_json::Value::Array(arr) => {
+                NodeValue::Array(arr.into_iter().map(NodeValue::from_json).collect())
+            }
+            serde_json::Value::Object(map) => NodeValue::from_object(
+                map.into_iter()
+                    .map(|(k, v)| (k, NodeValue::from_json(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    // Builds an object, recognising the tagged form of special variants
+    pub fn from_object(obj: IndexMap<String, NodeValue>) -> NodeValue {
+        if let (Some(NodeValue::String(tag)), 2) = (obj.get(TYPE_KEY), obj.len()) {
+            match (tag.as_str(), obj.get("path"), obj.get("base64")) {
+                ("file", Some(NodeValue::String(path)), _) => {
+                    return NodeValue::File { path: path.clone() };
+                }
+                ("bytes", _, Some(NodeValue::String(encoded))) => {
+                    if let Ok(bytes) = BASE64.decode(encoded) {
+                        return NodeValue::Bytes(bytes);
+                    }
+                }
+                _ => {}
+            }
+        }
+        NodeValue::Object(obj)
+    }
+
+    // Approximate memory held by the value, used for execution statistics
+    pub fn estimated_size(&self) -> usize {
+        let heap = match self {
+            NodeValue::String(s) => s.capacity(),
+            NodeValue::File { path } => path.capacity(),
+            NodeValue::Bytes(bytes) => bytes.capacity(),
+            NodeValue::Array(arr) => arr.iter().map(NodeValue::estimated_size).sum(),
+            NodeValue::Object(obj) => obj
+                .iter()
+                .map(|(k, v)| k.capacity() + v.estimated_size())
+                .sum(),
+            _ => 0,
+        };
+        std::mem::size_of::<NodeValue>() + heap
+    }
+
+    pub fn get(&self, key: &str) -> Option<&NodeValue> {
+        match self {
+            NodeValue::Object(obj) => obj.get(key),
+            _ => None,
         }
     }
 }
@@ -26,10 +151,64 @@ mod tests {
     #[test]
     fn test_string_coercion() {
         assert_eq!(NodeValue::Number(42.0).coerce_to_string(), "42");
+        assert_eq!(NodeValue::Integer(42).coerce_to_string(), "42");
         assert_eq!(NodeValue::Boolean(true).coerce_to_string(), "true");
         assert_eq!(
             NodeValue::String("hello".into()).coerce_to_string(),
             "hello"
         );
     }
+
+    #[test]
+    fn test_large_integers_round_trip() {
+        let json: serde_json::Value =
+            serde_json::from_str(r#"{"id": 9007199254740993, "ids": [9007199254740993]}"#).unwrap();
+        let value = NodeValue::from_json(json);
+        assert_eq!(
+            serde_json::to_string(&value.to_json_value()).unwrap(),
+            r#"{"id":9007199254740993,"ids":[9007199254740993]}"#
+        );
+
+        let top: NodeValue = serde_json::from_str("9007199254740993").unwrap();
+        assert!(matches!(top, NodeValue::Integer(9007199254740993)));
+
+        let max: NodeValue = serde_json::from_str("18446744073709551615").unwrap();
+        assert!(matches!(&max, NodeValue::String(s) if s == "18446744073709551615"));
+        let above: NodeValue = serde_json::from_str("9223372036854775808").unwrap();
+        assert_eq!(above.coerce_to_string(), "9223372036854775808");
+    }
+
+    #[test]
+    fn test_nested_objects_keep_order_and_files() {
+        let json = r#"{"z":1,"a":{"file":{"$type":"file","path":"/tmp/x"},"list":[{"k":true}]},"path":"p"}"#;
+        let value: NodeValue = serde_json::from_str(json).unwrap();
+
+        let inner = value.get("a").unwrap();
+        assert!(matches!(inner.get("file"), Some(NodeValue::File { path }) if path == "/tmp/x"));
+        // A plain object with a `path` key is not mistaken for a file
+        assert!(matches!(value, NodeValue::Object(_)));
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(value.coerce_to_string(), json);
+    }
+
+    #[test]
+    fn test_bytes_round_trip_as_base64() {
+        let value = NodeValue::Bytes(vec![0, 159, 146, 150, 255]);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"$type":"bytes","base64":"AJ+Slv8="}"#);
+
+        let back: NodeValue = serde_json::from_str(&json).unwrap();
+        assert!(matches!(back, NodeValue::Bytes(b) if b == vec![0, 159, 146, 150, 255]));
+        assert_eq!(
+            NodeValue::Bytes(vec![0; 2048]).preview(50),
+            "Bytes (2.0 KB)"
+        );
+    }
+
+    #[test]
+    fn test_number_previews() {
+        assert_eq!(NodeValue::Integer(42).preview(10), "42");
+        assert_eq!(NodeValue::Number(42.0).preview(10), "42.0");
+        assert_eq!(NodeValue::Number(42.5).preview(10), "42.5");
+    }
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use indexmap::IndexMap;
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
@@ -60,8 +61,8 @@ impl NodeExecutor for LlmChatExecutor {
                 if let NodeValue::Object(obj) = item {
                     if let (Some(role), Some(content)) = (obj.get("role"), obj.get("content")) {
                         messages.push(serde_json::json!({
-                            "role": role,
-                            "content": content
+                            "role": role.to_json_value(),
+                            "content": content.to_json_value()
                         }));
                     }
                 }
@@ -85,14 +86,14 @@ impl NodeExecutor for LlmChatExecutor {
         let history_out: Vec<NodeValue> = messages
             .iter()
             .map(|m| {
-                let mut obj = HashMap::new();
+                let mut obj = IndexMap::new();
                 obj.insert(
                     "role".to_string(),
-                    m.get("role").cloned().unwrap_or_default(),
+                    NodeValue::from_json(m.get("role").cloned().unwrap_or_default()),
                 );
                 obj.insert(
                     "content".to_string(),
-                    m.get("content").cloned().unwrap_or_default(),
+                    NodeValue::from_json(m.get("content").cloned().unwrap_or_default()),
                 );
                 NodeValue::Object(obj)
             })
@@ -0,0 +1,3 @@
+mod script;
+
+pub use script::{check_script, fold_script, CodeExecutor};
@@ -0,0 +1,334 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rhai::{Dynamic, Engine, Scope};
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::{NodeValue, TYPE_KEY};
+
+const DEFAULT_TIMEOUT_MS: u64 = 5_000;
+const DEFAULT_MAX_OPERATIONS: u64 = 10_000_000;
+const MAX_STRING_SIZE: usize = 10 * 1024 * 1024;
+const MAX_COLLECTION_SIZE: usize = 1_000_000;
+
+pub struct CodeExecutor;
+
+#[async_trait]
+impl NodeExecutor for CodeExecutor {
+    fn node_type(&self) -> &'static str {
+        "code"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let code = config
+            .get("code")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        if code.trim().is_empty() {
+            return Err(AppError::NodeExecution {
+                node_id: String::new(),
+                message: "No code provided".to_string(),
+            });
+        }
+
+        let timeout = Duration::from_millis(
+            config
+                .get("timeoutMs")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_TIMEOUT_MS),
+        );
+        let max_operations = config
+            .get("maxOperations")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_MAX_OPERATIONS);
+
+        let cancelled = ctx.cancelled.clone();
+
+        let (result, logs) = tokio::task::spawn_blocking(move || {
+            run_script(&code, inputs, timeout, max_operations, move || {
+                cancelled.load(Ordering::Relaxed)
+            })
+        })
+        .await
+        .map_err(|e| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Script task failed: {}", e),
+        })?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), result?);
+        outputs.insert(
+            "logs".to_string(),
+            NodeValue::Array(logs.into_iter().map(NodeValue::String).collect()),
+        );
+        Ok(outputs)
+    }
+}
+
+fn run_script(
+    code: &str,
+    inputs: HashMap<String, NodeValue>,
+    timeout: Duration,
+    max_operations: u64,
+    is_cancelled: impl Fn() -> bool + Send + Sync + 'static,
+) -> (Result<NodeValue, AppError>, Vec<String>) {
+    let logs = Arc::new(Mutex::new(Vec::new()));
+    let engine = build_engine(timeout, max_operations, is_cancelled, logs.clone());
+
+    // Each input is bound by name; `inputs` holds all of them for odd handle names
+    let result = (|| {
+        let ast = engine.compile(code).map_err(|e| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Script syntax error: {}", e),
+        })?;
+
+        let mut scope = Scope::new();
+        let mut all_inputs = rhai::Map::new();
+        for (name, value) in &inputs {
+            let dynamic = to_dynamic(value);
+            all_inputs.insert(name.as_str().into(), dynamic.clone());
+            if is_identifier(name) {
+                scope.push_dynamic(name.clone(), dynamic);
+            }
+        }
+        if !inputs.contains_key("input") {
+            scope.push_dynamic("input", Dynamic::UNIT);
+        }
+        scope.push_constant("inputs", all_inputs);
+
+        let value = engine
+            .eval_ast_with_scope::<Dynamic>(&mut scope, &ast)
+            .map_err(|e| AppError::NodeExecution {
+                node_id: String::new(),
+                message: format!("Script error: {}", e),
+            })?;
+
+        Ok(from_dynamic(value))
+    })();
+
+    let logs = std::mem::take(&mut *logs.lock().unwrap_or_else(|e| e.into_inner()));
+    (result, logs)
+}
+
+// Runs `code` once per item with `acc`, `item` and `index` in scope, the
+// value of each run becoming the next `acc`. Used by the Reduce node; the
+// accumulator stays a script value between items. The default timeout
+// covers the whole fold, while the operation limit applies to each item.
+pub fn fold_script(
+    code: &str,
+    initial: &NodeValue,
+    items: &[NodeValue],
+    keep_steps: bool,
+    is_cancelled: impl Fn() -> bool + Send + Sync + 'static,
+) -> Result<(NodeValue, Vec<NodeValue>), AppError> {
+    let logs = Arc::new(Mutex::new(Vec::new()));
+    let timeout = Duration::from_millis(DEFAULT_TIMEOUT_MS);
+    let engine = build_engine(timeout, DEFAULT_MAX_OPERATIONS, is_cancelled, logs);
+    let ast = engine.compile(code).map_err(|e| AppError::NodeExecution {
+        node_id: String::new(),
+        message: format!("Script syntax error: {}", e),
+    })?;
+
+    let mut acc = to_dynamic(initial);
+    let mut steps = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        let mut scope = Scope::new();
+        scope.push_dynamic("acc", acc);
+        scope.push_dynamic("item", to_dynamic(item));
+        scope.push("index", index as i64);
+        acc = engine
+            .eval_ast_with_scope::<Dynamic>(&mut scope, &ast)
+            .map_err(|e| AppError::NodeExecution {
+                node_id: String::new(),
+                message: format!("Script error at item {}: {}", index, e),
+            })?;
+        if keep_steps {
+            steps.push(from_dynamic(acc.clone()));
+        }
+    }
+    Ok((from_dynamic(acc), steps))
+}
+
+// Compiles `code` with the same sandboxed engine without running it, so a
+// node that embeds a script can report syntax errors up front
+pub fn check_script(code: &str) -> Result<(), AppError> {
+    let timeout = Duration::from_millis(DEFAULT_TIMEOUT_MS);
+    let engine = build_engine(timeout, DEFAULT_MAX_OPERATIONS, || false, Arc::default());
+    engine
+        .compile(code)
+        .map(|_| ())
+        .map_err(|e| AppError::Validation(format!("Script syntax error: {}", e)))
+}
+
+fn build_engine(
+    timeout: Duration,
+    max_operations: u64,
+    is_cancelled: impl Fn() -> bool + Send + Sync + 'static,
+    logs: Arc<Mutex<Vec<String>>>,
+) -> Engine {
+    let mut engine = Engine::new();
+
+    engine
+        .set_max_operations(max_operations)
+        .set_max_call_levels(64)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(MAX_COLLECTION_SIZE)
+        .set_max_map_size(MAX_COLLECTION_SIZE);
+
+    // Scripts must not be able to compile and run arbitrary strings
+    engine.disable_symbol("eval");
+
+    let start = Instant::now();
+    engine.on_progress(move |_| {
+        if is_cancelled() {
+            Some("Execution cancelled".into())
+        } else if start.elapsed() > timeout {
+            Some(format!("Script timed out after {} ms", timeout.as_millis()).into())
+        } else {
+            None
+        }
+    });
+
+    let print_logs = logs.clone();
+    engine.on_print(move |s| {
+        if let Ok(mut lock) = print_logs.lock() {
+            lock.push(s.to_string());
+        }
+    });
+    engine.on_debug(move |s, _, _| {
+        if let Ok(mut lock) = logs.lock() {
+            lock.push(s.to_string());
+        }
+    });
+
+    engine
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn to_dynamic(value: &NodeValue) -> Dynamic {
+    match value {
+        NodeValue::Null => Dynamic::UNIT,
+        NodeValue::Boolean(b) => Dynamic::from_bool(*b),
+        NodeValue::Integer(i) => Dynamic::from_int(*i),
+        NodeValue::Number(n) => Dynamic::from_float(*n),
+        NodeValue::String(s) => Dynamic::from(s.clone()),
+        NodeValue::Array(arr) => Dynamic::from_array(arr.iter().map(to_dynamic).collect()),
+        NodeValue::Object(obj) => Dynamic::from_map(
+            obj.iter()
+                .map(|(k, v)| (k.as_str().into(), to_dynamic(v)))
+                .collect(),
+        ),
+        // Files travel as their tagged map so scripts can pass them through
+        NodeValue::File { path } => Dynamic::from_map(
+            [
+                (TYPE_KEY.into(), Dynamic::from("file".to_string())),
+                ("path".into(), Dynamic::from(path.clone())),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+        NodeValue::Bytes(bytes) => Dynamic::from_blob(bytes.clone()),
+        NodeValue::Stream(stream) => Dynamic::from(stream.describe()),
+    }
+}
+
+fn from_dynamic(value: Dynamic) -> NodeValue {
+    if value.is_unit() {
+        NodeValue::Null
+    } else if let Ok(b) = value.as_bool() {
+        NodeValue::Boolean(b)
+    } else if let Ok(i) = value.as_int() {
+        NodeValue::Integer(i)
+    } else if let Ok(f) = value.as_float() {
+        NodeValue::Number(f)
+    } else if let Ok(c) = value.as_char() {
+        NodeValue::String(c.to_string())
+    } else if value.is_string() {
+        NodeValue::String(value.into_string().unwrap_or_default())
+    } else if value.is_blob() {
+        NodeValue::Bytes(value.cast::<rhai::Blob>())
+    } else if value.is_array() {
+        let arr = value.into_array().unwrap_or_default();
+        NodeValue::Array(arr.into_iter().map(from_dynamic).collect())
+    } else if value.is_map() {
+        let map = value.cast::<rhai::Map>();
+        NodeValue::from_object(
+            map.into_iter()
+                .map(|(k, v)| (k.to_string(), from_dynamic(v)))
+                .collect(),
+        )
+    } else {
+        NodeValue::String(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(code: &str, inputs: HashMap<String, NodeValue>) -> Result<NodeValue, AppError> {
+        let executor = CodeExecutor;
+        let config = serde_json::json!({ "code": code, "timeoutMs": 500 });
+        let ctx = ExecutionContext::new();
+        executor
+            .execute(inputs, config, &ctx)
+            .await
+            .map(|mut out| out.remove("output").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_code_returns_input() {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::String("hello".into()));
+        let result = run("return input;", inputs).await.unwrap();
+        assert_eq!(result.as_string().unwrap(), "hello");
+    }
+
+    #[tokio::test]
+    async fn test_code_builds_object() {
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::Array(vec![NodeValue::Number(1.0), NodeValue::Number(2.5)]),
+        );
+        let result = run("let total = 0.0; for x in input { total += x; } #{ total: total }", inputs)
+            .await
+            .unwrap();
+        if let NodeValue::Object(obj) = result {
+            assert!(matches!(obj.get("total"), Some(NodeValue::Number(n)) if *n == 3.5));
+        } else {
+            panic!("Expected object output");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_code_infinite_loop_times_out() {
+        let result = run("loop { }", HashMap::new()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_code_syntax_error() {
+        let result = run("let = ;", HashMap::new()).await;
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("syntax error"), "{message}");
+    }
+}
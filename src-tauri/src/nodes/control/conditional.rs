@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
+use crate::engine::expr::Expression;
 use crate::error::AppError;
 use crate::nodes::NodeExecutor;
 use crate::types::NodeValue;
@@ -14,21 +15,27 @@ impl NodeExecutor for ConditionalExecutor {
         "conditional"
     }
 
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        expression(config).map(|_| ())
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
-        _config: serde_json::Value,
+        config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
         let input = inputs.get("input").cloned().unwrap_or(NodeValue::Null);
 
-        let condition = inputs
-            .get("condition")
-            .and_then(|v| v.as_bool())
-            .unwrap_or_else(|| {
-                // Default: truthy check on input
-                input.as_bool().unwrap_or(false)
-            });
+        let expression = expression(&config)?;
+
+        // A connected condition input wins over the configured expression
+        let condition = match (inputs.get("condition"), expression) {
+            (Some(value), _) => value.as_bool().unwrap_or(false),
+            (None, Some(expression)) => expression.evaluate_bool(&[("input", &input)])?,
+            // Default: truthy check on input
+            (None, None) => input.as_bool().unwrap_or(false),
+        };
 
         let mut outputs = HashMap::new();
         if condition {
@@ -42,6 +49,16 @@ impl NodeExecutor for ConditionalExecutor {
     }
 }
 
+fn expression(config: &serde_json::Value) -> Result<Option<Expression>, AppError> {
+    config
+        .get("expression")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(Expression::parse)
+        .transpose()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +94,28 @@ mod tests {
         assert!(matches!(result.get("true").unwrap(), NodeValue::Null));
         assert!(matches!(result.get("false").unwrap(), NodeValue::String(_)));
     }
+
+    #[tokio::test]
+    async fn test_conditional_expression() {
+        let executor = ConditionalExecutor;
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::Number(12.0));
+
+        let ctx = ExecutionContext::new();
+        let result = executor
+            .execute(
+                inputs,
+                serde_json::json!({ "expression": "input > 10" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert!(matches!(result.get("true").unwrap(), NodeValue::Number(_)));
+        assert!(matches!(result.get("false").unwrap(), NodeValue::Null));
+
+        let err = executor
+            .validate_config(&serde_json::json!({ "expression": "input >" }))
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid expression"));
+    }
 }
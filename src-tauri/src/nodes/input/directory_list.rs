@@ -0,0 +1,326 @@
+use async_trait::async_trait;
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::path::Path;
+use walkdir::{DirEntry, WalkDir};
+
+use crate::engine::context::ExecutionContext;
+use crate::engine::paths;
+use crate::error::AppError;
+use crate::nodes::transform::split_list;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+const MAX_ENTRIES: usize = 100_000;
+
+pub struct DirectoryListExecutor;
+
+#[async_trait]
+impl NodeExecutor for DirectoryListExecutor {
+    fn node_type(&self) -> &'static str {
+        "directoryList"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        let path = config.get("path").and_then(|v| v.as_str()).unwrap_or("");
+        Patterns::new(config, split_glob(path).1.as_deref()).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let path = paths::path_setting(&inputs, &config, "path");
+        paths::check_path(&path)?;
+        let (root, path_glob) = split_glob(&path);
+        let patterns = Patterns::new(&config, path_glob.as_deref())?;
+        let recursive = config
+            .get("recursive")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        // A glob in the path decides how deep to look: `**` anywhere, or
+        // one level per segment
+        let max_depth = match &path_glob {
+            Some(glob) if glob.contains("**") => usize::MAX,
+            Some(glob) => glob.split('/').count(),
+            None if recursive => usize::MAX,
+            None => 1,
+        };
+        let root = root.to_string();
+        let include_dirs = config
+            .get("includeDirs")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let listed =
+            tokio::task::spawn_blocking(move || list(&root, &patterns, max_depth, include_dirs))
+                .await
+                .map_err(|e| list_error(format!("Directory listing failed: {}", e)))??;
+
+        let files = listed.iter().map(|entry| entry["file"].clone()).collect();
+        let mut outputs = HashMap::new();
+        outputs.insert("count".to_string(), NodeValue::Integer(listed.len() as i64));
+        outputs.insert("files".to_string(), NodeValue::Array(files));
+        outputs.insert(
+            "entries".to_string(),
+            NodeValue::Array(listed.into_iter().map(NodeValue::Object).collect()),
+        );
+        Ok(outputs)
+    }
+}
+
+// Patterns without a `/` match the file name at any depth (`*.csv`); with a
+// `/` they match the path relative to the listed directory
+// (`2024/**/*.csv`). `*` never crosses a `/`, `**` does. A glob taken from
+// the path always matches the relative path.
+struct Patterns {
+    path: Option<GlobSet>,
+    include: Option<(GlobSet, GlobSet)>,
+    exclude: (GlobSet, GlobSet),
+}
+
+impl Patterns {
+    fn new(config: &serde_json::Value, path_glob: Option<&str>) -> Result<Self, AppError> {
+        let list = |key: &str| -> Vec<&str> {
+            let spec = config.get(key).and_then(|v| v.as_str()).unwrap_or("");
+            split_list(spec)
+                .into_iter()
+                .map(str::trim)
+                .filter(|pattern| !pattern.is_empty())
+                .collect()
+        };
+        let include = list("include");
+        let path = match path_glob {
+            Some(pattern) => {
+                let mut set = GlobSetBuilder::new();
+                set.add(glob(pattern)?);
+                Some(finish(set)?)
+            }
+            None => None,
+        };
+        Ok(Patterns {
+            path,
+            include: if include.is_empty() {
+                None
+            } else {
+                Some(build(&include)?)
+            },
+            exclude: build(&list("exclude"))?,
+        })
+    }
+
+    fn matches(sets: &(GlobSet, GlobSet), name: &str, relative: &str) -> bool {
+        sets.0.is_match(name) || sets.1.is_match(relative)
+    }
+
+    fn included(&self, name: &str, relative: &str) -> bool {
+        self.path.as_ref().is_none_or(|set| set.is_match(relative))
+            && self
+                .include
+                .as_ref()
+                .is_none_or(|sets| Self::matches(sets, name, relative))
+    }
+
+    fn excluded(&self, name: &str, relative: &str) -> bool {
+        Self::matches(&self.exclude, name, relative)
+    }
+}
+
+// Returns the name patterns and the relative path patterns as two sets
+fn build(patterns: &[&str]) -> Result<(GlobSet, GlobSet), AppError> {
+    let mut names = GlobSetBuilder::new();
+    let mut relative = GlobSetBuilder::new();
+    for pattern in patterns {
+        if pattern.contains('/') {
+            relative.add(glob(pattern)?);
+        } else {
+            names.add(glob(pattern)?);
+        }
+    }
+    Ok((finish(names)?, finish(relative)?))
+}
+
+fn glob(pattern: &str) -> Result<Glob, AppError> {
+    GlobBuilder::new(pattern.trim_start_matches("./"))
+        .literal_separator(true)
+        .build()
+        .map_err(|e| AppError::Validation(format!("Invalid pattern '{}': {}", pattern, e)))
+}
+
+fn finish(builder: GlobSetBuilder) -> Result<GlobSet, AppError> {
+    builder
+        .build()
+        .map_err(|e| AppError::Validation(format!("Invalid pattern: {}", e)))
+}
+
+// A path such as `data/**/*.csv` lists `data` and keeps the entries that
+// match `**/*.csv`; the folder is everything before the first segment with
+// a wildcard
+fn split_glob(path: &str) -> (&str, Option<String>) {
+    let mut base_end = 0;
+    let mut start = 0;
+    for segment in path.split(['/', '\\']) {
+        if segment.contains(['*', '?', '[', '{']) {
+            let base = match &path[..base_end] {
+                "" if start > 0 => &path[..1],
+                "" => ".",
+                base => base,
+            };
+            return (base, Some(path[start..].replace('\\', "/")));
+        }
+        start += segment.len() + 1;
+        base_end = start - 1;
+    }
+    (path, None)
+}
+
+fn list(
+    root: &str,
+    patterns: &Patterns,
+    max_depth: usize,
+    include_dirs: bool,
+) -> Result<Vec<IndexMap<String, NodeValue>>, AppError> {
+    let root_path = Path::new(root);
+    if !root_path.is_dir() {
+        return Err(list_error(format!("'{}' is not a directory", root)));
+    }
+
+    let relative_path = |entry: &DirEntry| {
+        entry
+            .path()
+            .strip_prefix(root_path)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+
+    // Symlinks are not followed, and an excluded directory is not entered
+    let walker = WalkDir::new(root_path)
+        .min_depth(1)
+        .max_depth(max_depth)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            !patterns.excluded(&name, &relative_path(entry))
+        });
+
+    let mut listed = Vec::new();
+    for entry in walker {
+        let entry = entry.map_err(|e| list_error(format!("Failed to list '{}': {}", root, e)))?;
+        let is_dir = entry.file_type().is_dir();
+        let name = entry.file_name().to_string_lossy();
+        if (is_dir && !include_dirs) || !patterns.included(&name, &relative_path(&entry)) {
+            continue;
+        }
+        if listed.len() == MAX_ENTRIES {
+            return Err(list_error(format!(
+                "More than {} entries in '{}'; narrow the patterns",
+                MAX_ENTRIES, root
+            )));
+        }
+        let metadata = entry.metadata().map_err(|e| {
+            list_error(format!(
+                "Failed to read '{}': {}",
+                entry.path().display(),
+                e
+            ))
+        })?;
+        let mut described = paths::describe_file(entry.path(), &metadata);
+        described.shift_insert(
+            2,
+            "relativePath".to_string(),
+            NodeValue::String(relative_path(&entry)),
+        );
+        listed.push(described);
+    }
+    Ok(listed)
+}
+
+fn list_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_directory_list_with_patterns() {
+        let root = std::env::temp_dir().join(format!("signalflow_list_{}", std::process::id()));
+        for file in [
+            "a.csv",
+            "b.txt",
+            "2024/c.csv",
+            "2024/deep/d.CSV",
+            "node_modules/e.csv",
+        ] {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, "x,y\n").unwrap();
+        }
+        let ctx = ExecutionContext::new();
+        let run = |config: serde_json::Value| {
+            let mut config = config;
+            if config.get("path").is_none() {
+                config["path"] = serde_json::json!(root.to_string_lossy());
+            }
+            DirectoryListExecutor.execute(HashMap::new(), config, &ctx)
+        };
+        let relative_paths = |outputs: &HashMap<String, NodeValue>| -> Vec<String> {
+            match &outputs["entries"] {
+                NodeValue::Array(entries) => entries
+                    .iter()
+                    .map(|entry| {
+                        entry.to_json_value()["relativePath"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_string()
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            }
+        };
+
+        let top = run(serde_json::json!({})).await.unwrap();
+        assert_eq!(relative_paths(&top), ["a.csv", "b.txt"]);
+        assert!(matches!(&top["files"], NodeValue::Array(files)
+            if matches!(&files[0], NodeValue::File { path } if path.ends_with("a.csv"))));
+        let first = top["entries"].to_json_value()[0].clone();
+        assert_eq!(first["extension"], "csv");
+        assert_eq!(first["size"], 4);
+
+        let config = serde_json::json!({
+            "recursive": true,
+            "include": "*.csv, *.CSV",
+            "exclude": "node_modules"
+        });
+        let csv = run(config).await.unwrap();
+        assert_eq!(
+            relative_paths(&csv),
+            ["2024/c.csv", "2024/deep/d.CSV", "a.csv"]
+        );
+
+        let config = serde_json::json!({ "recursive": true, "include": "2024/*" });
+        let shallow = run(config).await.unwrap();
+        assert_eq!(relative_paths(&shallow), ["2024/c.csv"]);
+
+        // A glob in the path lists the folder before the first wildcard
+        let glob_path = |glob: &str| format!("{}/{}", root.to_string_lossy(), glob);
+        let config =
+            serde_json::json!({ "path": glob_path("**/*.csv"), "exclude": "node_modules" });
+        let globbed = run(config).await.unwrap();
+        assert_eq!(relative_paths(&globbed), ["2024/c.csv", "a.csv"]);
+        let config = serde_json::json!({ "path": glob_path("*/c.csv") });
+        let globbed = run(config).await.unwrap();
+        assert_eq!(relative_paths(&globbed), ["2024/c.csv"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -2,6 +2,9 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
+use crate::engine::encoding;
+use crate::engine::paths;
+use crate::engine::stream::ValueStream;
 use crate::error::AppError;
 use crate::nodes::NodeExecutor;
 use crate::types::NodeValue;
@@ -20,38 +23,122 @@ impl NodeExecutor for FileReadExecutor {
         config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
-        let path = inputs
-            .get("path")
-            .and_then(|v| v.as_string())
-            .or_else(|| config.get("path").and_then(|v| v.as_str()).map(String::from))
-            .unwrap_or_default();
-
-        if path.is_empty() {
-            return Err(AppError::NodeExecution {
-                node_id: String::new(),
-                message: "No file path provided".to_string(),
-            });
-        }
-
-        // Resolve to absolute path and validate no path traversal
-        let canonical = std::path::Path::new(&path);
-        if path.contains("..") {
-            return Err(AppError::NodeExecution {
-                node_id: String::new(),
-                message: "Path traversal not allowed".to_string(),
-            });
-        }
-
-        let content = tokio::fs::read_to_string(canonical).await.map_err(|e| {
-            AppError::NodeExecution {
-                node_id: String::new(),
-                message: format!("Failed to read file '{}': {}", path, e),
+        let path = paths::path_setting(&inputs, &config, "path");
+        let canonical = paths::check_path(&path)?;
+
+        let mode = config
+            .get("mode")
+            .and_then(|v| v.as_str())
+            .unwrap_or("text");
+        let encoding = config
+            .get("encoding")
+            .and_then(|v| v.as_str())
+            .unwrap_or(encoding::UTF8);
+        let read_error = |e: String| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Failed to read file '{}': {}", path, e),
+        };
+
+        let content = if mode == "stream" {
+            // Only check the file is there; consumers read it lazily
+            let metadata = tokio::fs::metadata(canonical)
+                .await
+                .map_err(|e| read_error(e.to_string()))?;
+            if !metadata.is_file() {
+                return Err(read_error("not a regular file".to_string()));
+            }
+            NodeValue::Stream(ValueStream::from_file(canonical, encoding))
+        } else {
+            let bytes = tokio::fs::read(canonical)
+                .await
+                .map_err(|e| read_error(e.to_string()))?;
+            if mode == "binary" {
+                NodeValue::Bytes(bytes)
+            } else {
+                NodeValue::String(encoding::decode_text(&bytes, encoding).map_err(read_error)?)
             }
-        })?;
+        };
 
         let mut outputs = HashMap::new();
-        outputs.insert("content".to_string(), NodeValue::String(content));
+        outputs.insert("content".to_string(), content);
         outputs.insert("file".to_string(), NodeValue::File { path });
         Ok(outputs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read(
+        path: &std::path::Path,
+        config: serde_json::Value,
+    ) -> Result<NodeValue, AppError> {
+        let mut config = config;
+        config["path"] = serde_json::json!(path.to_string_lossy());
+        let ctx = ExecutionContext::new();
+        let mut outputs = FileReadExecutor
+            .execute(HashMap::new(), config, &ctx)
+            .await?;
+        Ok(outputs.remove("content").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_read_binary_and_latin1() {
+        let path = std::env::temp_dir().join(format!("signalflow_read_{}.bin", std::process::id()));
+        tokio::fs::write(&path, b"caf\xE9\x00").await.unwrap();
+
+        let binary = read(&path, serde_json::json!({ "mode": "binary" }))
+            .await
+            .unwrap();
+        assert!(matches!(binary, NodeValue::Bytes(b) if b == b"caf\xE9\x00"));
+
+        let text = read(&path, serde_json::json!({ "encoding": "latin-1" }))
+            .await
+            .unwrap();
+        assert_eq!(text.as_string().unwrap(), "café\0");
+
+        // Strict UTF-8 reports a readable error instead of panicking or mangling data
+        let err = read(&path, serde_json::json!({})).await.unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_path_checks() {
+        // Only `..` segments are refused; a name with two dots is fine
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("signalflow_read_{}..v2.txt", std::process::id()));
+        tokio::fs::write(&path, "ok").await.unwrap();
+        let text = read(&path, serde_json::json!({})).await.unwrap();
+        assert_eq!(text.as_string().unwrap(), "ok");
+
+        // A File value on the path input wins over the setting
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "path".to_string(),
+            NodeValue::File {
+                path: path.to_string_lossy().into_owned(),
+            },
+        );
+        let config = serde_json::json!({ "path": "/missing.txt" });
+        let ctx = ExecutionContext::new();
+        let outputs = FileReadExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(outputs["content"].as_string().unwrap(), "ok");
+
+        let err = read(&dir.join("sub/../secrets.txt"), serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Path traversal not allowed"));
+        let err = read(std::path::Path::new(""), serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("No file path provided"));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
@@ -69,20 +69,69 @@ impl NodeExecutor for HttpRequestExecutor {
         }
 
         // Add body
-        if let Some(body) = inputs.get("body").and_then(|v| v.as_string()) {
-            req = req.body(body);
+        match inputs.get("body") {
+            Some(NodeValue::Bytes(bytes)) => req = req.body(bytes.clone()),
+            Some(body) => {
+                if let Some(body) = body.as_string() {
+                    req = req.body(body);
+                }
+            }
+            None => {}
         }
 
         let response = req.send().await.map_err(|e| AppError::Http(e.to_string()))?;
         let status = response.status().as_u16();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| AppError::Http(e.to_string()))?;
+        let response_type = config
+            .get("responseType")
+            .and_then(|v| v.as_str())
+            .unwrap_or("text");
+        let binary = match response_type {
+            "binary" => true,
+            "auto" => !is_text_content_type(response.headers()),
+            _ => false,
+        };
+        let body = if binary {
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|e| AppError::Http(e.to_string()))?;
+            NodeValue::Bytes(bytes.to_vec())
+        } else {
+            let text = response
+                .text()
+                .await
+                .map_err(|e| AppError::Http(e.to_string()))?;
+            NodeValue::String(text)
+        };
 
         let mut outputs = HashMap::new();
-        outputs.insert("response".to_string(), NodeValue::String(body));
-        outputs.insert("status".to_string(), NodeValue::Number(status as f64));
+        outputs.insert("response".to_string(), body);
+        outputs.insert("status".to_string(), NodeValue::Integer(status as i64));
         Ok(outputs)
     }
 }
+
+fn is_text_content_type(headers: &reqwest::header::HeaderMap) -> bool {
+    let Some(content_type) = headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return true;
+    };
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+    mime.starts_with("text/")
+        || mime.ends_with("+json")
+        || mime.ends_with("+xml")
+        || matches!(
+            mime.as_str(),
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/x-www-form-urlencoded"
+        )
+}
@@ -2,8 +2,12 @@ mod text_input;
 mod number_input;
 mod file_read;
 mod http_request;
+mod uuid_node;
+mod directory_list;
 
 pub use text_input::TextInputExecutor;
 pub use number_input::NumberInputExecutor;
 pub use file_read::FileReadExecutor;
 pub use http_request::HttpRequestExecutor;
+pub use uuid_node::UuidExecutor;
+pub use directory_list::DirectoryListExecutor;
@@ -20,13 +20,17 @@ impl NodeExecutor for NumberInputExecutor {
         config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
-        let value = config
-            .get("value")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(0.0);
+        // Whole numbers stay integers; anything else is a float
+        let value = match config.get("value") {
+            Some(v @ serde_json::Value::Number(_)) => NodeValue::from_json(v.clone()),
+            Some(serde_json::Value::String(s)) => {
+                NodeValue::parse_number(s).unwrap_or(NodeValue::Integer(0))
+            }
+            _ => NodeValue::Integer(0),
+        };
 
         let mut outputs = HashMap::new();
-        outputs.insert("value".to_string(), NodeValue::Number(value));
+        outputs.insert("value".to_string(), value);
         Ok(outputs)
     }
 }
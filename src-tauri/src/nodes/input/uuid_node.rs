@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::transform::config_int;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+const MAX_COUNT: i64 = 100_000;
+
+pub struct UuidExecutor;
+
+#[async_trait]
+impl NodeExecutor for UuidExecutor {
+    fn node_type(&self) -> &'static str {
+        "uuid"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        count(config)?;
+        match config.get("version").and_then(|v| v.as_str()) {
+            None | Some("v4") | Some("v7") => Ok(()),
+            Some(other) => Err(AppError::Validation(format!(
+                "Unknown UUID version '{}' (expected v4 or v7)",
+                other
+            ))),
+        }
+    }
+
+    async fn execute(
+        &self,
+        _inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        self.validate_config(&config)?;
+        // v7 UUIDs start with a timestamp, so they sort in creation order
+        let time_ordered = config.get("version").and_then(|v| v.as_str()) == Some("v7");
+        let generate = || {
+            let id = if time_ordered {
+                Uuid::now_v7()
+            } else {
+                Uuid::new_v4()
+            };
+            NodeValue::String(id.to_string())
+        };
+
+        // Without a count the node outputs a single UUID rather than an array
+        let value = match count(&config)? {
+            Some(n) => NodeValue::Array((0..n).map(|_| generate()).collect()),
+            None => generate(),
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("value".to_string(), value);
+        Ok(outputs)
+    }
+}
+
+fn count(config: &serde_json::Value) -> Result<Option<i64>, AppError> {
+    match config_int(config, "count")? {
+        Some(n) if !(1..=MAX_COUNT).contains(&n) => Err(AppError::Validation(format!(
+            "UUID count must be between 1 and {}",
+            MAX_COUNT
+        ))),
+        count => Ok(count),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_uuid_generation() {
+        let ctx = ExecutionContext::new();
+        let result = UuidExecutor
+            .execute(HashMap::new(), serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        let id = Uuid::parse_str(&result["value"].as_string().unwrap()).unwrap();
+        assert_eq!(id.get_version_num(), 4);
+
+        let config = serde_json::json!({ "version": "v7", "count": "3" });
+        let result = UuidExecutor
+            .execute(HashMap::new(), config, &ctx)
+            .await
+            .unwrap();
+        let ids: Vec<String> = match &result["value"] {
+            NodeValue::Array(items) => items.iter().map(|v| v.as_string().unwrap()).collect(),
+            other => panic!("expected an array, got {:?}", other),
+        };
+        assert_eq!(ids.len(), 3);
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -4,6 +4,7 @@ pub mod transform;
 pub mod output;
 pub mod control;
 pub mod ai;
+pub mod code;
 
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -16,6 +17,25 @@ use crate::types::NodeValue;
 pub trait NodeExecutor: Send + Sync {
     fn node_type(&self) -> &'static str;
 
+    // Nodes that can consume `NodeValue::Stream` inputs incrementally opt in
+    // here; all other nodes receive streams already read into memory.
+    fn accepts_streams(&self) -> bool {
+        false
+    }
+
+    // Input ports that take any number of connections. They receive an array
+    // of the connected values, in the order the edges were created; any
+    // other port accepts a single connection.
+    fn multi_input_ports(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    // Checks the configuration before the flow starts, so that a mistake
+    // such as an invalid query fails the run before any node executes.
+    fn validate_config(&self, _config: &serde_json::Value) -> Result<(), AppError> {
+        Ok(())
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
@@ -0,0 +1,447 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::engine::context::ExecutionContext;
+use crate::engine::paths;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct FileOpsExecutor;
+
+#[async_trait]
+impl NodeExecutor for FileOpsExecutor {
+    fn node_type(&self) -> &'static str {
+        "fileOps"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        Operation::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let operation = Operation::from_config(&config)?;
+        let path = paths::path_setting(&inputs, &config, "path");
+        paths::check_path(&path)?;
+
+        // Copy and move take the same path rules for their destination
+        let destination = match operation {
+            Operation::Copy | Operation::Move => {
+                let destination = paths::path_setting(&inputs, &config, "destination");
+                paths::check_path(&destination)?;
+                Some(PathBuf::from(destination))
+            }
+            _ => None,
+        };
+        let flag = |key: &str| config.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        let overwrite = flag("overwrite");
+        let recursive = flag("recursive");
+
+        let (file, result) = tokio::task::spawn_blocking(move || {
+            let path = Path::new(&path);
+            match (operation, destination) {
+                (Operation::Copy, Some(to)) => transfer(path, &to, overwrite, false),
+                (Operation::Move, Some(to)) => transfer(path, &to, overwrite, true),
+                (Operation::Delete, _) => delete(path, recursive),
+                (Operation::Mkdir, _) => mkdir(path),
+                (Operation::Exists, _) => path
+                    .try_exists()
+                    .map(|exists| (path.to_path_buf(), NodeValue::Boolean(exists)))
+                    .map_err(|e| ops_error(path, "check", e)),
+                (Operation::Stat, _) => fs::metadata(path)
+                    .map(|metadata| {
+                        let info = paths::describe_file(path, &metadata);
+                        (path.to_path_buf(), NodeValue::Object(info))
+                    })
+                    .map_err(|e| ops_error(path, "read", e)),
+                (_, None) => unreachable!("copy and move always have a destination"),
+            }
+        })
+        .await
+        .map_err(|e| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("File operation failed: {}", e),
+        })??;
+
+        let mut outputs = HashMap::new();
+        outputs.insert(
+            "file".to_string(),
+            NodeValue::File {
+                path: file.to_string_lossy().into_owned(),
+            },
+        );
+        outputs.insert("result".to_string(), result);
+        Ok(outputs)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Copy,
+    Move,
+    Delete,
+    Mkdir,
+    Exists,
+    Stat,
+}
+
+impl Operation {
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        match config
+            .get("operation")
+            .and_then(|v| v.as_str())
+            .unwrap_or("exists")
+        {
+            "copy" => Ok(Operation::Copy),
+            // Renaming is a move within the same folder
+            "move" | "rename" => Ok(Operation::Move),
+            "delete" => Ok(Operation::Delete),
+            "mkdir" => Ok(Operation::Mkdir),
+            "exists" => Ok(Operation::Exists),
+            "stat" => Ok(Operation::Stat),
+            other => Err(AppError::Validation(format!(
+                "Unknown file operation '{}' (expected copy, move, delete, mkdir, exists or stat)",
+                other
+            ))),
+        }
+    }
+}
+
+// Copies or moves a file or folder. A destination that is an existing
+// folder receives the source under its own name, like `cp` and `mv`;
+// missing parent folders are created. An existing target is only replaced
+// with `overwrite` on, and only once the new copy is complete: the source
+// goes to a hidden sibling of the target first and is renamed over it.
+fn transfer(
+    from: &Path,
+    to: &Path,
+    overwrite: bool,
+    remove_source: bool,
+) -> Result<(PathBuf, NodeValue), AppError> {
+    let verb = if remove_source { "move" } else { "copy" };
+    let source = fs::symlink_metadata(from).map_err(|e| ops_error(from, verb, e))?;
+    let target = match from.file_name() {
+        Some(name) if to.is_dir() => to.join(name),
+        _ => to.to_path_buf(),
+    };
+    let (Some(parent), Some(name)) = (target.parent(), target.file_name()) else {
+        return Err(ops_message(format!(
+            "Cannot {} onto '{}'",
+            verb,
+            target.display()
+        )));
+    };
+
+    // A file replaced by itself would be lost, and a folder copied or moved
+    // into itself would never finish
+    let inside_source = match (fs::canonicalize(from), resolve(parent)) {
+        (Ok(from), Some(folder)) => {
+            folder.join(name) == from || (source.is_dir() && folder.starts_with(&from))
+        }
+        _ => false,
+    };
+    if inside_source {
+        return Err(ops_message(format!(
+            "Cannot {} '{}' onto itself or into its own folder",
+            verb,
+            from.display()
+        )));
+    }
+
+    let replace = fs::symlink_metadata(&target).is_ok();
+    if replace && !overwrite {
+        return Err(ops_message(format!(
+            "'{}' already exists; enable Overwrite to replace it",
+            target.display()
+        )));
+    }
+
+    if !parent.as_os_str().is_empty() {
+        fs::create_dir_all(parent).map_err(|e| ops_error(parent, "create", e))?;
+    }
+    let staging = parent.join(format!(
+        ".{}.signalflow-{}",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    let moved = stage(from, &staging, source.is_dir(), remove_source)
+        .map_err(|e| ops_error(from, verb, e))?;
+    let finished = if replace {
+        remove(&target, true).map_err(|e| ops_error(&target, "replace", e))
+    } else {
+        Ok(())
+    }
+    .and_then(|_| fs::rename(&staging, &target).map_err(|e| ops_error(from, verb, e)));
+    if let Err(e) = finished {
+        // A moved source goes back where it came from; a copy is discarded
+        let _ = if moved {
+            fs::rename(&staging, from)
+        } else {
+            remove(&staging, true)
+        };
+        return Err(e);
+    }
+    if remove_source && !moved {
+        remove(from, true).map_err(|e| ops_error(from, "remove", e))?;
+    }
+
+    let target_text = NodeValue::String(target.to_string_lossy().into_owned());
+    Ok((target, target_text))
+}
+
+// Canonicalizes the part of `path` that exists and appends the rest, so a
+// destination folder can be compared before it is created
+fn resolve(path: &Path) -> Option<PathBuf> {
+    path.ancestors().find_map(|ancestor| {
+        let rest = path.strip_prefix(ancestor).ok()?;
+        let existing = if ancestor.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            ancestor
+        };
+        fs::canonicalize(existing)
+            .ok()
+            .map(|found| found.join(rest))
+    })
+}
+
+// Puts the source at `staging` and tells whether it was moved there by a
+// rename. Across drives a move is a copy, and the source is deleted later.
+fn stage(from: &Path, staging: &Path, is_dir: bool, remove_source: bool) -> std::io::Result<bool> {
+    if remove_source {
+        match fs::rename(from, staging) {
+            Ok(()) => return Ok(true),
+            Err(e) if e.kind() == ErrorKind::CrossesDevices => {}
+            Err(e) => return Err(e),
+        }
+    }
+    copy_all(from, staging, is_dir).inspect_err(|_| {
+        let _ = remove(staging, true);
+    })?;
+    Ok(false)
+}
+
+fn copy_all(from: &Path, to: &Path, is_dir: bool) -> std::io::Result<()> {
+    if !is_dir {
+        return fs::copy(from, to).map(|_| ());
+    }
+    for entry in WalkDir::new(from) {
+        let entry = entry.map_err(std::io::Error::other)?;
+        let relative = entry.path().strip_prefix(from).unwrap_or(entry.path());
+        let target = to.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+// Deleting a missing path succeeds with `false`, so a cleanup step can run
+// more than once. Folders with contents need `recursive`.
+fn delete(path: &Path, recursive: bool) -> Result<(PathBuf, NodeValue), AppError> {
+    if path.parent().is_none() {
+        return Err(ops_message(format!(
+            "Refusing to delete the root folder '{}'",
+            path.display()
+        )));
+    }
+    match fs::symlink_metadata(path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            return Ok((path.to_path_buf(), NodeValue::Boolean(false)))
+        }
+        Err(e) => return Err(ops_error(path, "delete", e)),
+        Ok(metadata) if metadata.is_dir() && !recursive => {
+            fs::remove_dir(path).map_err(|e| match e.kind() {
+                ErrorKind::DirectoryNotEmpty => ops_message(format!(
+                    "'{}' is not empty; enable Recursive to delete it with its contents",
+                    path.display()
+                )),
+                _ => ops_error(path, "delete", e),
+            })?;
+        }
+        Ok(_) => remove(path, recursive).map_err(|e| ops_error(path, "delete", e))?,
+    }
+    Ok((path.to_path_buf(), NodeValue::Boolean(true)))
+}
+
+// Symlinks are removed themselves, never the folder they point to
+fn remove(path: &Path, recursive: bool) -> std::io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        if recursive {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_dir(path)
+        }
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+// Creates the folder and any missing parents; the result tells whether it
+// had to be created
+fn mkdir(path: &Path) -> Result<(PathBuf, NodeValue), AppError> {
+    let existed = path.is_dir();
+    fs::create_dir_all(path).map_err(|e| ops_error(path, "create", e))?;
+    Ok((path.to_path_buf(), NodeValue::Boolean(!existed)))
+}
+
+fn ops_error(path: &Path, verb: &str, e: std::io::Error) -> AppError {
+    ops_message(format!("Failed to {} '{}': {}", verb, path.display(), e))
+}
+
+fn ops_message(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(
+        operation: &str,
+        path: &Path,
+        extra: serde_json::Value,
+    ) -> Result<NodeValue, AppError> {
+        let mut config = serde_json::json!({
+            "operation": operation,
+            "path": path.to_string_lossy(),
+        });
+        if let serde_json::Value::Object(extra) = extra {
+            for (key, value) in extra {
+                config[key] = value;
+            }
+        }
+        let ctx = ExecutionContext::new();
+        let mut outputs = FileOpsExecutor
+            .execute(HashMap::new(), config, &ctx)
+            .await?;
+        Ok(outputs.remove("result").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_file_operations() {
+        let root = std::env::temp_dir().join(format!("signalflow_ops_{}", std::process::id()));
+        let inbox = root.join("inbox/2024");
+        let archive = root.join("archive");
+
+        let created = run("mkdir", &inbox, serde_json::json!({})).await.unwrap();
+        assert!(matches!(created, NodeValue::Boolean(true)));
+        let report = inbox.join("report.csv");
+        fs::write(&report, "a,b\n").unwrap();
+
+        // Copying into an existing folder keeps the name; a second copy
+        // needs overwrite
+        fs::create_dir_all(&archive).unwrap();
+        let to_archive = serde_json::json!({ "destination": archive.to_string_lossy() });
+        run("copy", &report, to_archive.clone()).await.unwrap();
+        let err = run("copy", &report, to_archive.clone()).await.unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let renamed = root.join("renamed/report-final.csv");
+        let moved = run(
+            "move",
+            &archive.join("report.csv"),
+            serde_json::json!({ "destination": renamed.to_string_lossy() }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(moved.as_string().unwrap(), renamed.to_string_lossy());
+        let exists = run("exists", &archive.join("report.csv"), serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(matches!(exists, NodeValue::Boolean(false)));
+
+        let info = run("stat", &renamed, serde_json::json!({})).await.unwrap();
+        let info = info.to_json_value();
+        assert_eq!(info["name"], "report-final.csv");
+        assert_eq!(info["extension"], "csv");
+        assert_eq!(info["size"], 4);
+
+        let into_itself = serde_json::json!({ "destination": inbox.to_string_lossy() });
+        let err = run("copy", &root.join("inbox"), into_itself)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("into its own folder"));
+
+        let err = run("delete", &root.join("inbox"), serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not empty"));
+        let err = run(
+            "delete",
+            &root.join("inbox/../archive"),
+            serde_json::json!({}),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("Path traversal not allowed"));
+
+        let deleted = run("delete", &root, serde_json::json!({ "recursive": true }))
+            .await
+            .unwrap();
+        assert!(matches!(deleted, NodeValue::Boolean(true)));
+        let deleted = run("delete", &root, serde_json::json!({})).await.unwrap();
+        assert!(matches!(deleted, NodeValue::Boolean(false)));
+    }
+
+    #[tokio::test]
+    async fn test_replacing_a_file_with_itself_keeps_it() {
+        let root = std::env::temp_dir().join(format!("signalflow_same_{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("report.csv");
+        fs::write(&path, "a,b\n").unwrap();
+        for operation in ["copy", "move"] {
+            for destination in [&path, &root] {
+                let onto_itself = serde_json::json!({ "destination": destination.to_string_lossy(), "overwrite": true });
+                let err = run(operation, &path, onto_itself).await.unwrap_err();
+                assert!(err.to_string().contains("onto itself"), "{}", operation);
+                assert_eq!(fs::read_to_string(&path).unwrap(), "a,b\n");
+            }
+        }
+
+        // Refused transfers leave no new folders behind
+        let nested = root.join("missing/deeper");
+        let err = run(
+            "move",
+            &root,
+            serde_json::json!({ "destination": nested.to_string_lossy() }),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("into its own folder"));
+        let existing = root.join("existing/old.csv");
+        fs::create_dir_all(existing.parent().unwrap()).unwrap();
+        fs::write(&existing, "old\n").unwrap();
+        let err = run(
+            "copy",
+            &path,
+            serde_json::json!({ "destination": existing.to_string_lossy() }),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert!(!root.join("missing").exists());
+
+        // Replacing another file swaps in the new content in one step
+        let replace =
+            serde_json::json!({ "destination": existing.to_string_lossy(), "overwrite": true });
+        run("move", &path, replace).await.unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "a,b\n");
+        assert!(!path.exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,7 +1,13 @@
 use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
 use std::collections::HashMap;
+use tokio::io::AsyncWriteExt;
 
 use crate::engine::context::ExecutionContext;
+use crate::engine::encoding;
+use crate::engine::paths;
+use crate::engine::stream::ValueStream;
 use crate::error::AppError;
 use crate::nodes::NodeExecutor;
 use crate::types::NodeValue;
@@ -14,45 +20,57 @@ impl NodeExecutor for FileWriteExecutor {
         "fileWrite"
     }
 
+    fn accepts_streams(&self) -> bool {
+        true
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
         config: serde_json::Value,
-        _ctx: &ExecutionContext,
+        ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
-        let path = inputs
-            .get("path")
-            .and_then(|v| v.as_string())
-            .or_else(|| config.get("path").and_then(|v| v.as_str()).map(String::from))
-            .unwrap_or_default();
-
-        if path.is_empty() {
-            return Err(AppError::NodeExecution {
-                node_id: String::new(),
-                message: "No file path provided".to_string(),
-            });
-        }
-
-        // Validate no path traversal
-        if path.contains("..") {
-            return Err(AppError::NodeExecution {
-                node_id: String::new(),
-                message: "Path traversal not allowed".to_string(),
-            });
-        }
-
-        let content = inputs
-            .get("content")
-            .map(|v| v.coerce_to_string())
-            .unwrap_or_default();
+        let path = paths::path_setting(&inputs, &config, "path");
+        paths::check_path(&path)?;
 
+        let content = inputs.get("content").cloned().unwrap_or_default();
+        let mode = config
+            .get("mode")
+            .and_then(|v| v.as_str())
+            .unwrap_or("text");
+        let encoding = config
+            .get("encoding")
+            .and_then(|v| v.as_str())
+            .unwrap_or(encoding::UTF8);
         let append = config
             .get("append")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
+        let mut outputs = HashMap::new();
+        if let NodeValue::Stream(stream) = &content {
+            write_stream(stream, &path, append, encoding, ctx).await?;
+            outputs.insert("file".to_string(), NodeValue::File { path });
+            return Ok(outputs);
+        }
+
+        let bytes = match (&content, mode) {
+            // Bytes are always written as-is, whatever the mode
+            (NodeValue::Bytes(bytes), _) => bytes.clone(),
+            // In binary mode a string is taken to be base64
+            (NodeValue::String(s), "binary") => {
+                BASE64
+                    .decode(s.trim())
+                    .map_err(|e| AppError::NodeExecution {
+                        node_id: String::new(),
+                        message: format!("Binary content must be bytes or base64: {}", e),
+                    })?
+            }
+            _ => encoding::encode_text(&content.coerce_to_string(), encoding)
+                .map_err(|e| encode_error(&path, e))?,
+        };
+
         if append {
-            use tokio::io::AsyncWriteExt;
             let mut file = tokio::fs::OpenOptions::new()
                 .create(true)
                 .append(true)
@@ -62,11 +80,11 @@ impl NodeExecutor for FileWriteExecutor {
                     node_id: String::new(),
                     message: format!("Failed to open file '{}': {}", path, e),
                 })?;
-            file.write_all(content.as_bytes())
+            file.write_all(&bytes)
                 .await
                 .map_err(|e| AppError::Io(e.to_string()))?;
         } else {
-            tokio::fs::write(&path, &content)
+            tokio::fs::write(&path, &bytes)
                 .await
                 .map_err(|e| AppError::NodeExecution {
                     node_id: String::new(),
@@ -74,8 +92,133 @@ impl NodeExecutor for FileWriteExecutor {
                 })?;
         }
 
-        let mut outputs = HashMap::new();
         outputs.insert("file".to_string(), NodeValue::File { path });
         Ok(outputs)
     }
 }
+
+// Text streams are written chunk by chunk; record streams one record per line
+async fn write_stream(
+    stream: &ValueStream,
+    path: &str,
+    append: bool,
+    encoding: &str,
+    ctx: &ExecutionContext,
+) -> Result<(), AppError> {
+    let file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .await
+        .map_err(|e| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Failed to open file '{}': {}", path, e),
+        })?;
+    let mut writer = tokio::io::BufWriter::new(file);
+    let mut reader = stream.open().await?;
+    let records = !stream.is_text();
+
+    while let Some(item) = reader.next().await? {
+        if ctx.is_cancelled() {
+            return Err(AppError::Cancelled);
+        }
+        let mut text = item.coerce_to_string();
+        if records {
+            text.push('\n');
+        }
+        let bytes = encoding::encode_text(&text, encoding).map_err(|e| encode_error(path, e))?;
+        writer
+            .write_all(&bytes)
+            .await
+            .map_err(|e| AppError::Io(e.to_string()))?;
+    }
+    writer
+        .flush()
+        .await
+        .map_err(|e| AppError::Io(e.to_string()))
+}
+
+fn encode_error(path: &str, message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message: format!("Failed to encode content for '{}': {}", path, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::transform::{FilterExecutor, SplitExecutor};
+
+    #[tokio::test]
+    async fn test_stream_pipeline_writes_records() {
+        let dir = std::env::temp_dir();
+        let source = dir.join(format!("signalflow_pipeline_{}.log", std::process::id()));
+        let target = dir.join(format!("signalflow_pipeline_{}.out", std::process::id()));
+        let log: String = (0..5000)
+            .map(|i| format!("{} {}\n", if i % 3 == 0 { "ERROR" } else { "INFO" }, i))
+            .collect();
+        tokio::fs::write(&source, &log).await.unwrap();
+
+        let ctx = ExecutionContext::new();
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::Stream(ValueStream::from_file(&source, "utf-8")),
+        );
+        let mut split = SplitExecutor
+            .execute(inputs, serde_json::json!({ "delimiter": "\n" }), &ctx)
+            .await
+            .unwrap();
+
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), split.remove("output").unwrap());
+        let config = serde_json::json!({ "condition": "item.startsWith('ERROR')" });
+        let filtered = FilterExecutor.execute(inputs, config, &ctx).await.unwrap();
+        assert!(matches!(filtered.get("output"), Some(NodeValue::Stream(_))));
+
+        let mut inputs = HashMap::new();
+        inputs.insert("content".to_string(), filtered["output"].clone());
+        let config = serde_json::json!({ "path": target.to_string_lossy() });
+        FileWriteExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+
+        let written = tokio::fs::read_to_string(&target).await.unwrap();
+        assert_eq!(written.lines().count(), 1667);
+        assert!(written.starts_with("ERROR 0\nERROR 3\n"));
+
+        tokio::fs::remove_file(&source).await.unwrap();
+        tokio::fs::remove_file(&target).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_path_checks() {
+        let ctx = ExecutionContext::new();
+        let dir = std::env::temp_dir();
+        let write = |path: std::path::PathBuf| {
+            let mut inputs = HashMap::new();
+            inputs.insert("content".to_string(), NodeValue::String("ok".to_string()));
+            let config = serde_json::json!({ "path": path.to_string_lossy() });
+            FileWriteExecutor.execute(inputs, config, &ctx)
+        };
+
+        // Only `..` segments are refused; a name with two dots is fine
+        let path = dir.join(format!("signalflow_write_{}..v2.txt", std::process::id()));
+        write(path.clone()).await.unwrap();
+        assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "ok");
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        let escaped = format!("signalflow_write_{}.txt", std::process::id());
+        let err = write(dir.join("sub").join("..").join(&escaped))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Path traversal not allowed"));
+        assert!(!dir.join(&escaped).exists());
+        let err = write(std::path::PathBuf::new()).await.unwrap_err();
+        assert!(err.to_string().contains("No file path provided"));
+    }
+}
@@ -1,5 +1,7 @@
 mod debug;
 mod file_write;
+mod file_ops;
 
 pub use debug::DebugExecutor;
 pub use file_write::FileWriteExecutor;
+pub use file_ops::FileOpsExecutor;
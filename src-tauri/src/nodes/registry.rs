@@ -6,6 +6,7 @@ use super::transform::*;
 use super::output::*;
 use super::control::*;
 use super::ai::*;
+use super::code::*;
 
 pub struct NodeRegistry {
     executors: HashMap<String, Box<dyn NodeExecutor>>,
@@ -20,19 +21,55 @@ impl NodeRegistry {
         executors.insert("numberInput".to_string(), Box::new(NumberInputExecutor));
         executors.insert("fileRead".to_string(), Box::new(FileReadExecutor));
         executors.insert("httpRequest".to_string(), Box::new(HttpRequestExecutor));
+        executors.insert("uuid".to_string(), Box::new(UuidExecutor));
+        executors.insert("directoryList".to_string(), Box::new(DirectoryListExecutor));
 
         // Transform nodes
         executors.insert("textTemplate".to_string(), Box::new(TextTemplateExecutor));
         executors.insert("jsonParse".to_string(), Box::new(JsonParseExecutor));
+        executors.insert("jsonSerialize".to_string(), Box::new(JsonSerializeExecutor));
         executors.insert("regex".to_string(), Box::new(RegexExecutor));
         executors.insert("filter".to_string(), Box::new(FilterExecutor));
         executors.insert("map".to_string(), Box::new(MapExecutor));
         executors.insert("merge".to_string(), Box::new(MergeExecutor));
         executors.insert("split".to_string(), Box::new(SplitExecutor));
+        executors.insert("csvParse".to_string(), Box::new(CsvParseExecutor));
+        executors.insert("csvSerialize".to_string(), Box::new(CsvSerializeExecutor));
+        executors.insert("yamlParse".to_string(), Box::new(YamlParseExecutor));
+        executors.insert("yamlSerialize".to_string(), Box::new(YamlSerializeExecutor));
+        executors.insert("tomlParse".to_string(), Box::new(TomlParseExecutor));
+        executors.insert("tomlSerialize".to_string(), Box::new(TomlSerializeExecutor));
+        executors.insert("xmlParse".to_string(), Box::new(XmlParseExecutor));
+        executors.insert("xmlSerialize".to_string(), Box::new(XmlSerializeExecutor));
+        executors.insert("query".to_string(), Box::new(QueryExecutor));
+        executors.insert("sort".to_string(), Box::new(SortExecutor));
+        executors.insert("groupBy".to_string(), Box::new(GroupByExecutor));
+        executors.insert("aggregate".to_string(), Box::new(AggregateExecutor));
+        executors.insert("dedupe".to_string(), Box::new(DedupeExecutor));
+        executors.insert("join".to_string(), Box::new(JoinExecutor));
+        executors.insert("objectTransform".to_string(), Box::new(ObjectTransformExecutor));
+        executors.insert("flatten".to_string(), Box::new(FlattenExecutor));
+        executors.insert("chunk".to_string(), Box::new(ChunkExecutor));
+        executors.insert("zip".to_string(), Box::new(ZipExecutor));
+        executors.insert("slice".to_string(), Box::new(SliceExecutor));
+        executors.insert("reverse".to_string(), Box::new(ReverseExecutor));
+        executors.insert("unique".to_string(), Box::new(UniqueExecutor));
+        executors.insert("length".to_string(), Box::new(LengthExecutor));
+        executors.insert("reduce".to_string(), Box::new(ReduceExecutor));
+        executors.insert("textTransform".to_string(), Box::new(TextTransformExecutor));
+        executors.insert("math".to_string(), Box::new(MathExecutor));
+        executors.insert("dateTime".to_string(), Box::new(DateTimeExecutor));
+        executors.insert("encode".to_string(), Box::new(EncodeExecutor));
+        executors.insert("hash".to_string(), Box::new(HashExecutor));
+        executors.insert("hmac".to_string(), Box::new(HmacExecutor));
+        executors.insert("compress".to_string(), Box::new(CompressExecutor));
+        executors.insert("archiveExtract".to_string(), Box::new(ArchiveExtractExecutor));
+        executors.insert("archiveCreate".to_string(), Box::new(ArchiveCreateExecutor));
 
         // Output nodes
         executors.insert("debug".to_string(), Box::new(DebugExecutor));
         executors.insert("fileWrite".to_string(), Box::new(FileWriteExecutor));
+        executors.insert("fileOps".to_string(), Box::new(FileOpsExecutor));
 
         // Control nodes
         executors.insert("conditional".to_string(), Box::new(ConditionalExecutor));
@@ -41,6 +78,9 @@ impl NodeRegistry {
         executors.insert("llmPrompt".to_string(), Box::new(LlmPromptExecutor));
         executors.insert("llmChat".to_string(), Box::new(LlmChatExecutor));
 
+        // Code nodes
+        executors.insert("code".to_string(), Box::new(CodeExecutor));
+
         Self { executors }
     }
 
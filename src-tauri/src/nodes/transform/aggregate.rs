@@ -0,0 +1,336 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::fields::{array_input, config_path, field_value, split_list, ValueGroups, ValueSet};
+use crate::engine::context::ExecutionContext;
+use crate::engine::expr::compare_values;
+use crate::engine::jsonpath::JsonPath;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct AggregateExecutor;
+
+#[async_trait]
+impl NodeExecutor for AggregateExecutor {
+    fn node_type(&self) -> &'static str {
+        "aggregate"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        config_path(config, "groupBy")?;
+        parse_aggregations(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Aggregate")?;
+        let group_path = config_path(&config, "groupBy")?;
+        let aggregations = parse_aggregations(&config)?;
+
+        // Without a group field the whole array is one group and the output
+        // is a single object; otherwise one object per group, in order of
+        // first appearance, starting with the group key
+        let output = match &group_path {
+            None => NodeValue::Object(summarize(items.iter(), &aggregations)?),
+            Some(path) => {
+                let key_name = config
+                    .get("groupBy")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .trim()
+                    .to_string();
+                let mut groups = ValueGroups::default();
+                for item in items {
+                    groups.push(field_value(item, Some(path))?, item);
+                }
+                let groups = groups.into_groups();
+                let mut rows = Vec::with_capacity(groups.len());
+                for (key, members) in groups {
+                    let mut row = IndexMap::new();
+                    row.insert(key_name.clone(), key);
+                    row.extend(summarize(members.into_iter(), &aggregations)?);
+                    rows.push(NodeValue::Object(row));
+                }
+                NodeValue::Array(rows)
+            }
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+    Distinct,
+}
+
+struct Aggregation {
+    op: Op,
+    field: Option<JsonPath>,
+    name: String,
+}
+
+// Aggregations are written as a list separated by commas or newlines, such
+// as `count(), sum(price), avg(price) as avgPrice, distinct(tags[0])`
+fn parse_aggregations(config: &serde_json::Value) -> Result<Vec<Aggregation>, AppError> {
+    let spec = config
+        .get("aggregations")
+        .and_then(|v| v.as_str())
+        .unwrap_or("count()");
+    // Each output row starts with the group key under the groupBy name
+    let group_key = config
+        .get("groupBy")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .trim();
+
+    let mut aggregations = Vec::new();
+    for part in split_list(spec) {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let invalid = |reason: &str| {
+            AppError::Validation(format!("Invalid aggregation '{}': {}", part, reason))
+        };
+
+        let (call, alias) = match part.rsplit_once(" as ") {
+            Some((call, alias)) if !alias.trim().is_empty() => (call.trim(), Some(alias.trim())),
+            _ => (part, None),
+        };
+        let (op_name, field) = match call.split_once('(') {
+            Some((op_name, rest)) => {
+                let field = rest
+                    .strip_suffix(')')
+                    .ok_or_else(|| invalid("missing ')'"))?;
+                (op_name.trim(), field.trim())
+            }
+            None => (call, ""),
+        };
+        let op = match op_name {
+            "count" => Op::Count,
+            "sum" => Op::Sum,
+            "avg" => Op::Avg,
+            "min" => Op::Min,
+            "max" => Op::Max,
+            "distinct" => Op::Distinct,
+            _ => return Err(invalid("expected count, sum, avg, min, max or distinct")),
+        };
+        if field.is_empty() && op != Op::Count {
+            return Err(invalid("a field is required"));
+        }
+
+        let name = match alias {
+            Some(alias) => alias.to_string(),
+            None if field.is_empty() => op_name.to_string(),
+            None => {
+                let field: String = field
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                    .collect();
+                format!("{}_{}", op_name, field.trim_matches('_'))
+            }
+        };
+        if !group_key.is_empty() && name == group_key {
+            return Err(invalid(&format!(
+                "'{}' is already the group key; give it another name with 'as'",
+                name
+            )));
+        }
+        let field = if field.is_empty() {
+            None
+        } else {
+            Some(JsonPath::parse(field)?)
+        };
+        aggregations.push(Aggregation { op, field, name });
+    }
+
+    if aggregations.is_empty() {
+        return Err(AppError::Validation(
+            "Aggregate needs at least one aggregation".to_string(),
+        ));
+    }
+    Ok(aggregations)
+}
+
+fn summarize<'a>(
+    items: impl Iterator<Item = &'a NodeValue> + Clone,
+    aggregations: &[Aggregation],
+) -> Result<IndexMap<String, NodeValue>, AppError> {
+    let mut row = IndexMap::new();
+    for aggregation in aggregations {
+        // `count()` counts items; with a field, missing (null) values are
+        // ignored, as in SQL
+        let Some(path) = &aggregation.field else {
+            row.insert(
+                aggregation.name.clone(),
+                NodeValue::Integer(items.clone().count() as i64),
+            );
+            continue;
+        };
+        let values = items
+            .clone()
+            .map(|item| field_value(item, Some(path)))
+            .filter(|v| !matches!(v, Ok(NodeValue::Null)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let value = match aggregation.op {
+            Op::Count => NodeValue::Integer(values.len() as i64),
+            Op::Sum => sum(&values, &aggregation.name)?,
+            Op::Avg => {
+                if values.is_empty() {
+                    NodeValue::Null
+                } else {
+                    let total = sum(&values, &aggregation.name)?.as_number().unwrap_or(0.0);
+                    NodeValue::Number(total / values.len() as f64)
+                }
+            }
+            Op::Min => extreme(values, Ordering::Less),
+            Op::Max => extreme(values, Ordering::Greater),
+            Op::Distinct => {
+                let mut seen = ValueSet::default();
+                NodeValue::Array(values.into_iter().filter(|v| seen.insert(v)).collect())
+            }
+        };
+        row.insert(aggregation.name.clone(), value);
+    }
+    Ok(row)
+}
+
+// Integers sum exactly unless they overflow; any other number makes the
+// result a float
+fn sum(values: &[NodeValue], name: &str) -> Result<NodeValue, AppError> {
+    let mut integer: Option<i64> = Some(0);
+    let mut float = 0.0;
+    for value in values {
+        let n = value.as_number().ok_or_else(|| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!(
+                "Cannot aggregate '{}': '{}' is not a number",
+                name,
+                value.coerce_to_string()
+            ),
+        })?;
+        integer = match value {
+            NodeValue::Integer(i) => integer.and_then(|total| total.checked_add(*i)),
+            _ => None,
+        };
+        float += n;
+    }
+    Ok(match integer {
+        Some(total) => NodeValue::Integer(total),
+        None => NodeValue::Number(float),
+    })
+}
+
+fn extreme(values: Vec<NodeValue>, wanted: Ordering) -> NodeValue {
+    values
+        .into_iter()
+        .reduce(|best, value| {
+            let ordering = compare_values(&value, &best)
+                .unwrap_or_else(|| value.coerce_to_string().cmp(&best.coerce_to_string()));
+            if ordering == wanted {
+                value
+            } else {
+                best
+            }
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_aggregate_per_group() {
+        let sales = NodeValue::from_json(serde_json::json!([
+            { "region": "north", "amount": 10, "rep": "ann" },
+            { "region": "south", "amount": 2.5, "rep": "bob" },
+            { "region": "north", "amount": 5, "rep": "ann" },
+            { "region": "north", "rep": "cy" }
+        ]));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), sales);
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({
+            "groupBy": "region",
+            "aggregations": "count(), sum(amount), avg(amount) as mean, max(amount), distinct(rep) as reps"
+        });
+        let result = AggregateExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([
+                { "region": "north", "count": 3, "sum_amount": 15, "mean": 7.5, "max_amount": 10, "reps": ["ann", "cy"] },
+                { "region": "south", "count": 1, "sum_amount": 2.5, "mean": 2.5, "max_amount": 2.5, "reps": ["bob"] }
+            ])
+        );
+
+        let err = AggregateExecutor
+            .validate_config(&serde_json::json!({ "aggregations": "median(amount)" }))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Invalid aggregation 'median(amount)'"));
+    }
+
+    #[test]
+    fn test_aggregations_split_on_lines() {
+        // Commas inside brackets and quotes belong to the field
+        let config = serde_json::json!({
+            "aggregations": "count()\n  sum($['net, eur']) as net\r\nmax(amount), min(amount)\n"
+        });
+        let names: Vec<String> = parse_aggregations(&config)
+            .unwrap()
+            .into_iter()
+            .map(|aggregation| aggregation.name)
+            .collect();
+        assert_eq!(names, ["count", "net", "max_amount", "min_amount"]);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_keeps_keys_of_different_types_apart() {
+        let items = NodeValue::from_json(serde_json::json!([
+            { "k": 1 }, { "k": "1" }, { "k": 1.0 }, { "k": null },
+            { "k": "" }, { "k": true }, { "k": "true" }, {}
+        ]));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), items);
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "groupBy": "k", "aggregations": "count() as n" });
+        let result = AggregateExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([
+                { "k": 1, "n": 2 }, { "k": "1", "n": 1 }, { "k": null, "n": 2 },
+                { "k": "", "n": 1 }, { "k": true, "n": 1 }, { "k": "true", "n": 1 }
+            ])
+        );
+
+        let err = AggregateExecutor
+            .validate_config(
+                &serde_json::json!({ "groupBy": "region", "aggregations": "count() as region" }),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("already the group key"));
+    }
+}
@@ -0,0 +1,423 @@
+use async_trait::async_trait;
+use chrono::{Datelike, Timelike};
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::compression::{bytes_value, compression_error, read_limited, MAX_DECOMPRESSED_SIZE};
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct ArchiveExtractExecutor;
+
+#[async_trait]
+impl NodeExecutor for ArchiveExtractExecutor {
+    fn node_type(&self) -> &'static str {
+        "archiveExtract"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        match config.get("format").and_then(|v| v.as_str()) {
+            None | Some("auto") => Ok(()),
+            Some(format) => Format::parse(format).map(|_| ()),
+        }
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let data = inputs
+            .get("input")
+            .cloned()
+            .unwrap_or_default()
+            .coerce_to_bytes();
+        let format = match config.get("format").and_then(|v| v.as_str()) {
+            None | Some("auto") => Format::detect(&data),
+            Some(format) => Format::parse(format)?,
+        };
+        // Listing reads only the names and sizes, without unpacking anything
+        let list_only = config.get("mode").and_then(|v| v.as_str()) == Some("list");
+        let entries =
+            tokio::task::spawn_blocking(move || extract(&data, format, list_only, &config))
+                .await
+                .map_err(|e| compression_error(format!("Archive extraction failed: {}", e)))??;
+
+        let mut outputs = HashMap::new();
+        outputs.insert(
+            "count".to_string(),
+            NodeValue::Integer(entries.len() as i64),
+        );
+        outputs.insert("entries".to_string(), NodeValue::Array(entries));
+        Ok(outputs)
+    }
+}
+
+pub struct ArchiveCreateExecutor;
+
+#[async_trait]
+impl NodeExecutor for ArchiveCreateExecutor {
+    fn node_type(&self) -> &'static str {
+        "archiveCreate"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        create_format(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let format = create_format(&config)?;
+
+        // Entries use the same `{name, content}` shape Archive Extract
+        // outputs; text content is stored as UTF-8 and other values as JSON
+        let mut files = Vec::new();
+        let entries = match inputs.get("entries") {
+            Some(NodeValue::Array(items)) => items.as_slice(),
+            Some(NodeValue::Null) | None => &[],
+            Some(_) => {
+                return Err(compression_error(
+                    "Archive Create expects an array of {name, content} objects".to_string(),
+                ))
+            }
+        };
+        for (i, item) in entries.iter().enumerate() {
+            let NodeValue::Object(fields) = item else {
+                return Err(compression_error(format!(
+                    "Archive entry {} is not a {{name, content}} object",
+                    i
+                )));
+            };
+            let name = fields
+                .get("name")
+                .map(|v| v.coerce_to_string())
+                .unwrap_or_default();
+            check_entry_name(&name)?;
+            let content = fields
+                .get("content")
+                .cloned()
+                .unwrap_or_default()
+                .coerce_to_bytes();
+            files.push((name, content));
+        }
+
+        let archive = tokio::task::spawn_blocking(move || match format {
+            Format::Zip => write_zip(&files),
+            Format::Tar => write_tar(&files, Vec::new()),
+            Format::TarGz => write_tar(&files, GzEncoder::new(Vec::new(), Compression::default()))
+                .and_then(|encoder| encoder.finish()),
+        })
+        .await
+        .map_err(|e| compression_error(format!("Archive creation failed: {}", e)))?
+        .map_err(|e| compression_error(format!("Failed to create archive: {}", e)))?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Bytes(archive));
+        Ok(outputs)
+    }
+}
+
+fn extract(
+    data: &[u8],
+    format: Format,
+    list_only: bool,
+    config: &serde_json::Value,
+) -> Result<Vec<NodeValue>, AppError> {
+    let mut entries = Vec::new();
+    let mut total: u64 = 0;
+    let mut add = |name: String, size: u64, content: Option<Vec<u8>>| {
+        let mut entry = IndexMap::new();
+        entry.insert("name".to_string(), NodeValue::String(name));
+        match content {
+            Some(content) => {
+                total += content.len() as u64;
+                if total > MAX_DECOMPRESSED_SIZE {
+                    return Err(compression_error(format!(
+                        "Extracted entries exceed {} MB",
+                        MAX_DECOMPRESSED_SIZE / 1024 / 1024
+                    )));
+                }
+                entry.insert("content".to_string(), bytes_value(content, config)?);
+            }
+            None => {
+                entry.insert("size".to_string(), NodeValue::Integer(size as i64));
+            }
+        }
+        entries.push(NodeValue::Object(entry));
+        Ok(())
+    };
+
+    // Directories, links and other special entries are skipped. Names
+    // are checked like Archive Create's, so a name such as
+    // `../../.bashrc` cannot steer a later File Write elsewhere.
+    match format {
+        Format::Zip => {
+            let mut archive = ZipArchive::new(Cursor::new(data))
+                .map_err(|e| compression_error(format!("Invalid zip archive: {}", e)))?;
+            for i in 0..archive.len() {
+                let file = archive
+                    .by_index(i)
+                    .map_err(|e| compression_error(format!("Invalid zip entry: {}", e)))?;
+                if !file.is_file() {
+                    continue;
+                }
+                let name = file.name().to_string();
+                if file.enclosed_name().is_none() || !is_relative_name(&name) {
+                    return Err(unsafe_entry(&name));
+                }
+                let size = file.size();
+                let content = if list_only {
+                    None
+                } else {
+                    Some(read_limited(file, "zip")?)
+                };
+                add(name, size, content)?;
+            }
+        }
+        Format::Tar | Format::TarGz => {
+            let reader: Box<dyn Read> = match format {
+                Format::TarGz => Box::new(MultiGzDecoder::new(data)),
+                _ => Box::new(data),
+            };
+            let mut archive = tar::Archive::new(reader);
+            let tar_error =
+                |e: std::io::Error| compression_error(format!("Invalid tar archive: {}", e));
+            for entry in archive.entries().map_err(tar_error)? {
+                let entry = entry.map_err(tar_error)?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+                let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+                if !is_relative_name(&name) {
+                    return Err(unsafe_entry(&name));
+                }
+                let size = entry.size();
+                let content = if list_only {
+                    None
+                } else {
+                    Some(read_limited(entry, "tar")?)
+                };
+                add(name, size, content)?;
+            }
+        }
+    }
+    Ok(entries)
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl Format {
+    fn parse(format: &str) -> Result<Self, AppError> {
+        match format {
+            "zip" => Ok(Format::Zip),
+            "tar" => Ok(Format::Tar),
+            "tar.gz" | "tgz" => Ok(Format::TarGz),
+            other => Err(AppError::Validation(format!(
+                "Unknown archive format '{}' (expected zip, tar or tar.gz)",
+                other
+            ))),
+        }
+    }
+
+    // Zip and gzip start with fixed magic bytes; anything else is read as tar
+    fn detect(data: &[u8]) -> Self {
+        if data.starts_with(b"PK\x03\x04") || data.starts_with(b"PK\x05\x06") {
+            Format::Zip
+        } else if data.starts_with(&[0x1f, 0x8b]) {
+            Format::TarGz
+        } else {
+            Format::Tar
+        }
+    }
+}
+
+fn create_format(config: &serde_json::Value) -> Result<Format, AppError> {
+    Format::parse(
+        config
+            .get("format")
+            .and_then(|v| v.as_str())
+            .unwrap_or("zip"),
+    )
+}
+
+// Names are relative paths with `/` separators; anything that could unpack
+// outside the target directory is refused
+fn is_relative_name(name: &str) -> bool {
+    let escapes = name.starts_with('/')
+        || name.contains('\\')
+        || name.split('/').any(|part| part == "..")
+        || name.chars().nth(1) == Some(':');
+    !name.trim().is_empty() && !name.ends_with('/') && !escapes
+}
+
+fn check_entry_name(name: &str) -> Result<(), AppError> {
+    if !is_relative_name(name) {
+        return Err(compression_error(format!(
+            "Invalid archive entry name '{}': use a relative path such as 'data/report.csv'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+fn unsafe_entry(name: &str) -> AppError {
+    compression_error(format!(
+        "Archive entry '{}' is not a relative path inside the archive; refusing to extract it",
+        name
+    ))
+}
+
+fn write_zip(files: &[(String, Vec<u8>)]) -> std::io::Result<Vec<u8>> {
+    let now = chrono::Local::now();
+    let modified = zip::DateTime::from_date_and_time(
+        now.year() as u16,
+        now.month() as u8,
+        now.day() as u8,
+        now.hour() as u8,
+        now.minute() as u8,
+        now.second() as u8,
+    )
+    .unwrap_or_default();
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .last_modified_time(modified);
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        writer.start_file(name.as_str(), options)?;
+        writer.write_all(content)?;
+    }
+    Ok(writer.finish()?.into_inner())
+}
+
+fn write_tar<W: Write>(files: &[(String, Vec<u8>)], out: W) -> std::io::Result<W> {
+    let modified = chrono::Utc::now().timestamp().max(0) as u64;
+    let mut builder = tar::Builder::new(out);
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(modified);
+        builder.append_data(&mut header, name, content.as_slice())?;
+    }
+    builder.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_archive_round_trip() {
+        let entries = NodeValue::from_json(serde_json::json!([
+            { "name": "report.csv", "content": "id,total\n1,9.5\n" },
+            { "name": "data/items.json", "content": [1, 2] }
+        ]));
+        let ctx = ExecutionContext::new();
+        for format in ["zip", "tar", "tar.gz"] {
+            let mut inputs = HashMap::new();
+            inputs.insert("entries".to_string(), entries.clone());
+            let config = serde_json::json!({ "format": format });
+            let created = ArchiveCreateExecutor
+                .execute(inputs, config, &ctx)
+                .await
+                .unwrap();
+
+            // The format is detected from the data
+            let mut inputs = HashMap::new();
+            inputs.insert("input".to_string(), created["output"].clone());
+            let result = ArchiveExtractExecutor
+                .execute(inputs.clone(), serde_json::json!({}), &ctx)
+                .await
+                .unwrap();
+            assert_eq!(
+                result["entries"].to_json_value(),
+                serde_json::json!([
+                    { "name": "report.csv", "content": "id,total\n1,9.5\n" },
+                    { "name": "data/items.json", "content": "[1,2]" }
+                ]),
+                "{}",
+                format
+            );
+
+            let config = serde_json::json!({ "mode": "list" });
+            let result = ArchiveExtractExecutor
+                .execute(inputs, config, &ctx)
+                .await
+                .unwrap();
+            assert_eq!(
+                result["entries"].to_json_value()[1],
+                serde_json::json!({ "name": "data/items.json", "size": 5 }),
+                "{}",
+                format
+            );
+        }
+
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "entries".to_string(),
+            NodeValue::from_json(serde_json::json!([{ "name": "../escape.txt", "content": "" }])),
+        );
+        let err = ArchiveCreateExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid archive entry name"));
+
+        // Extracted names that escape the archive are refused as well
+        for name in [
+            "../escape.txt",
+            "/etc/cron.d/job",
+            "C:/Windows/x.dll",
+            "a\\..\\b",
+        ] {
+            let files = [(name.to_string(), b"x".to_vec())];
+            let mut inputs = HashMap::new();
+            inputs.insert(
+                "input".to_string(),
+                NodeValue::Bytes(write_zip(&files).unwrap()),
+            );
+            let err = ArchiveExtractExecutor
+                .execute(inputs, serde_json::json!({ "mode": "list" }), &ctx)
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("refusing to extract"), "{}", name);
+        }
+
+        // The tar builder refuses `..` itself, so the header is written by hand
+        let mut header = tar::Header::new_gnu();
+        header.as_old_mut().name[..9].copy_from_slice(b"../x.conf");
+        header.set_size(1);
+        header.set_cksum();
+        let mut builder = tar::Builder::new(Vec::new());
+        builder.append(&header, &b"x"[..]).unwrap();
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::Bytes(builder.into_inner().unwrap()),
+        );
+        let err = ArchiveExtractExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("'../x.conf'"));
+    }
+}
@@ -0,0 +1,518 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+use super::fields::{array_input, config_int, config_path, field_value, split_list, ValueSet};
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct FlattenExecutor;
+
+#[async_trait]
+impl NodeExecutor for FlattenExecutor {
+    fn node_type(&self) -> &'static str {
+        "flatten"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        flatten_depth(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Flatten")?;
+        let depth = flatten_depth(&config)?;
+
+        let mut flat = Vec::new();
+        flatten_into(&mut flat, items, depth);
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(flat));
+        Ok(outputs)
+    }
+}
+
+// Depth defaults to one level; 0 flattens all the way down
+fn flatten_depth(config: &serde_json::Value) -> Result<usize, AppError> {
+    match config_int(config, "depth")? {
+        None => Ok(1),
+        Some(0) => Ok(usize::MAX),
+        Some(depth) if depth > 0 => Ok(depth as usize),
+        Some(_) => Err(AppError::Validation(
+            "Flatten depth cannot be negative".to_string(),
+        )),
+    }
+}
+
+fn flatten_into(flat: &mut Vec<NodeValue>, items: &[NodeValue], depth: usize) {
+    for item in items {
+        match item {
+            NodeValue::Array(inner) if depth > 0 => flatten_into(flat, inner, depth - 1),
+            other => flat.push(other.clone()),
+        }
+    }
+}
+
+pub struct ChunkExecutor;
+
+#[async_trait]
+impl NodeExecutor for ChunkExecutor {
+    fn node_type(&self) -> &'static str {
+        "chunk"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        chunk_size(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Chunk")?;
+        let size = chunk_size(&config)?;
+
+        // The last batch holds whatever is left over
+        let batches: Vec<NodeValue> = items
+            .chunks(size)
+            .map(|batch| NodeValue::Array(batch.to_vec()))
+            .collect();
+
+        let mut outputs = HashMap::new();
+        outputs.insert(
+            "count".to_string(),
+            NodeValue::Integer(batches.len() as i64),
+        );
+        outputs.insert("output".to_string(), NodeValue::Array(batches));
+        Ok(outputs)
+    }
+}
+
+fn chunk_size(config: &serde_json::Value) -> Result<usize, AppError> {
+    match config_int(config, "size")? {
+        Some(size) if size > 0 => Ok(size as usize),
+        _ => Err(AppError::Validation(
+            "Chunk size must be at least 1".to_string(),
+        )),
+    }
+}
+
+pub struct ZipExecutor;
+
+#[async_trait]
+impl NodeExecutor for ZipExecutor {
+    fn node_type(&self) -> &'static str {
+        "zip"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        zip_names(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let side = |port: &str| match inputs.get(port) {
+            Some(NodeValue::Array(items)) => Ok(items),
+            _ => Err(AppError::NodeExecution {
+                node_id: String::new(),
+                message: format!("Zip expects an array on '{}'", port),
+            }),
+        };
+        let left = side("left")?;
+        let right = side("right")?;
+        let names = zip_names(&config)?;
+
+        // By default zipping stops at the shorter array; "longest" pads the
+        // shorter one with nulls
+        let len = match config.get("length").and_then(|v| v.as_str()) {
+            Some("longest") => left.len().max(right.len()),
+            _ => left.len().min(right.len()),
+        };
+        let zipped = (0..len)
+            .map(|i| {
+                let a = left.get(i).cloned().unwrap_or_default();
+                let b = right.get(i).cloned().unwrap_or_default();
+                match &names {
+                    Some((left_name, right_name)) => {
+                        let mut pair = IndexMap::new();
+                        pair.insert(left_name.clone(), a);
+                        pair.insert(right_name.clone(), b);
+                        NodeValue::Object(pair)
+                    }
+                    None => NodeValue::Array(vec![a, b]),
+                }
+            })
+            .collect();
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(zipped));
+        Ok(outputs)
+    }
+}
+
+// With field names such as `question, answer` each pair becomes an object;
+// without, a two-item array
+fn zip_names(config: &serde_json::Value) -> Result<Option<(String, String)>, AppError> {
+    let spec = config.get("names").and_then(|v| v.as_str()).unwrap_or("");
+    if spec.trim().is_empty() {
+        return Ok(None);
+    }
+    match split_list(spec)
+        .into_iter()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [left, right] if !left.is_empty() && !right.is_empty() && left != right => {
+            Ok(Some((left.to_string(), right.to_string())))
+        }
+        _ => Err(AppError::Validation(format!(
+            "Zip field names must be two different names, got '{}'",
+            spec
+        ))),
+    }
+}
+
+pub struct SliceExecutor;
+
+#[async_trait]
+impl NodeExecutor for SliceExecutor {
+    fn node_type(&self) -> &'static str {
+        "slice"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        config_int(config, "start")?;
+        config_int(config, "end").map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Slice")?;
+        let len = items.len() as i64;
+
+        // Indices work as in JavaScript's `slice`: the end is exclusive,
+        // negative values count from the end and out-of-range values are
+        // clamped. Take the first N with end = N, skip N with start = N.
+        let resolve = |index: i64| {
+            let index = if index < 0 { len + index } else { index };
+            index.clamp(0, len) as usize
+        };
+        let start = resolve(config_int(&config, "start")?.unwrap_or(0));
+        let end = resolve(config_int(&config, "end")?.unwrap_or(len));
+        let sliced = items[start..end.max(start)].to_vec();
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(sliced));
+        Ok(outputs)
+    }
+}
+
+pub struct ReverseExecutor;
+
+#[async_trait]
+impl NodeExecutor for ReverseExecutor {
+    fn node_type(&self) -> &'static str {
+        "reverse"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        _config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Reverse")?;
+        let reversed = items.iter().rev().cloned().collect();
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(reversed));
+        Ok(outputs)
+    }
+}
+
+pub struct UniqueExecutor;
+
+#[async_trait]
+impl NodeExecutor for UniqueExecutor {
+    fn node_type(&self) -> &'static str {
+        "unique"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        config_path(config, "field").map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Unique")?;
+        let path = config_path(&config, "field")?;
+
+        // Unlike Dedupe, which keeps whole records, this outputs the
+        // distinct values themselves (of a field, if one is given), in order
+        // of first appearance. Missing fields are skipped.
+        let mut seen = ValueSet::default();
+        let mut values = Vec::new();
+        for item in items {
+            let value = field_value(item, path.as_ref())?;
+            if !(path.is_some() && matches!(value, NodeValue::Null)) && seen.insert(&value) {
+                values.push(value);
+            }
+        }
+
+        let mut outputs = HashMap::new();
+        outputs.insert("count".to_string(), NodeValue::Integer(values.len() as i64));
+        outputs.insert("output".to_string(), NodeValue::Array(values));
+        Ok(outputs)
+    }
+}
+
+pub struct LengthExecutor;
+
+#[async_trait]
+impl NodeExecutor for LengthExecutor {
+    fn node_type(&self) -> &'static str {
+        "length"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        _config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        // Items of an array, characters of a text, fields of an object or
+        // bytes of binary data
+        let length = match inputs.get("input") {
+            None | Some(NodeValue::Null) => 0,
+            Some(NodeValue::Array(items)) => items.len(),
+            Some(NodeValue::String(s)) => s.chars().count(),
+            Some(NodeValue::Object(obj)) => obj.len(),
+            Some(NodeValue::Bytes(bytes)) => bytes.len(),
+            Some(other) => {
+                return Err(AppError::NodeExecution {
+                    node_id: String::new(),
+                    message: format!(
+                        "Length expects an array, text, object or bytes, got '{}'",
+                        other.coerce_to_string()
+                    ),
+                })
+            }
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Integer(length as i64));
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array_inputs(value: serde_json::Value) -> HashMap<String, NodeValue> {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::from_json(value));
+        inputs
+    }
+
+    #[tokio::test]
+    async fn test_flatten_depth() {
+        let ctx = ExecutionContext::new();
+        let nested = serde_json::json!([1, [2, [3, [4]]], [], 5]);
+        let result = FlattenExecutor
+            .execute(array_inputs(nested.clone()), serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([1, 2, [3, [4]], 5])
+        );
+        let result = FlattenExecutor
+            .execute(
+                array_inputs(nested),
+                serde_json::json!({ "depth": 0 }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([1, 2, 3, 4, 5])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunk_batches() {
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "size": "2" });
+        let result = ChunkExecutor
+            .execute(
+                array_inputs(serde_json::json!([1, 2, 3, 4, 5])),
+                config,
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([[1, 2], [3, 4], [5]])
+        );
+        assert!(matches!(result["count"], NodeValue::Integer(3)));
+        assert!(ChunkExecutor
+            .validate_config(&serde_json::json!({ "size": 0 }))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_zip_pairs_and_objects() {
+        let ctx = ExecutionContext::new();
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "left".to_string(),
+            NodeValue::from_json(serde_json::json!(["q1", "q2", "q3"])),
+        );
+        inputs.insert(
+            "right".to_string(),
+            NodeValue::from_json(serde_json::json!(["a1", "a2"])),
+        );
+        let result = ZipExecutor
+            .execute(inputs.clone(), serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([["q1", "a1"], ["q2", "a2"]])
+        );
+
+        let config = serde_json::json!({ "length": "longest", "names": "question, answer" });
+        let result = ZipExecutor.execute(inputs, config, &ctx).await.unwrap();
+        assert_eq!(
+            result["output"].to_json_value()[2],
+            serde_json::json!({ "question": "q3", "answer": null })
+        );
+    }
+
+    async fn slice(config: serde_json::Value) -> serde_json::Value {
+        let ctx = ExecutionContext::new();
+        let inputs = array_inputs(serde_json::json!([0, 1, 2, 3, 4]));
+        let result = SliceExecutor.execute(inputs, config, &ctx).await.unwrap();
+        result["output"].to_json_value()
+    }
+
+    #[tokio::test]
+    async fn test_slice_take_and_skip() {
+        assert_eq!(
+            slice(serde_json::json!({ "end": 2 })).await,
+            serde_json::json!([0, 1])
+        );
+        assert_eq!(
+            slice(serde_json::json!({ "start": 3 })).await,
+            serde_json::json!([3, 4])
+        );
+        assert_eq!(
+            slice(serde_json::json!({ "start": -3, "end": -1 })).await,
+            serde_json::json!([2, 3])
+        );
+        assert_eq!(
+            slice(serde_json::json!({ "start": 4, "end": 1 })).await,
+            serde_json::json!([])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reverse() {
+        let ctx = ExecutionContext::new();
+        let result = ReverseExecutor
+            .execute(
+                array_inputs(serde_json::json!([1, "two", null])),
+                serde_json::json!({}),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([null, "two", 1])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unique_values_of_field() {
+        let ctx = ExecutionContext::new();
+        let records = serde_json::json!([
+            { "tag": "a" }, { "tag": "b" }, { "other": 1 }, { "tag": "a" }
+        ]);
+        let result = UniqueExecutor
+            .execute(
+                array_inputs(records),
+                serde_json::json!({ "field": "tag" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!(["a", "b"])
+        );
+        assert!(matches!(result["count"], NodeValue::Integer(2)));
+
+        let result = UniqueExecutor
+            .execute(
+                array_inputs(serde_json::json!([1, 1.0, "1", null, null])),
+                serde_json::json!({}),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([1, "1", null])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_length() {
+        let ctx = ExecutionContext::new();
+        for (input, expected) in [
+            (serde_json::json!([1, 2, 3]), 3),
+            (serde_json::json!("héllo"), 5),
+            (serde_json::json!({ "a": 1 }), 1),
+            (serde_json::json!(null), 0),
+        ] {
+            let result = LengthExecutor
+                .execute(array_inputs(input), serde_json::json!({}), &ctx)
+                .await
+                .unwrap();
+            assert!(matches!(result["output"], NodeValue::Integer(n) if n == expected));
+        }
+        assert!(LengthExecutor
+            .execute(
+                array_inputs(serde_json::json!(42)),
+                serde_json::json!({}),
+                &ctx
+            )
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use super::fields::config_int;
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+// Decompressed data is held in memory, so a small archive that expands to
+// gigabytes is stopped rather than allowed to exhaust it
+pub(super) const MAX_DECOMPRESSED_SIZE: u64 = 512 * 1024 * 1024;
+
+pub struct CompressExecutor;
+
+#[async_trait]
+impl NodeExecutor for CompressExecutor {
+    fn node_type(&self) -> &'static str {
+        "compress"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        level(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let (zstd, level) = level(&config)?;
+        let decompress = config.get("direction").and_then(|v| v.as_str()) == Some("decompress");
+        let data = inputs
+            .get("input")
+            .cloned()
+            .unwrap_or_default()
+            .coerce_to_bytes();
+
+        // Level 22 zstd or a 512 MB gzip stream takes a while, so the work
+        // runs off the async runtime
+        let output = tokio::task::spawn_blocking(move || {
+            if decompress {
+                let bytes = if zstd {
+                    let decoder = zstd::stream::read::Decoder::new(data.as_slice())
+                        .map_err(|e| compression_error(format!("Invalid zstd data: {}", e)))?;
+                    read_limited(decoder, "zstd")?
+                } else {
+                    read_limited(MultiGzDecoder::new(data.as_slice()), "gzip")?
+                };
+                bytes_value(bytes, &config)
+            } else {
+                let compressed = if zstd {
+                    zstd::encode_all(data.as_slice(), level as i32)
+                } else {
+                    let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level as u32));
+                    encoder.write_all(&data).and_then(|_| encoder.finish())
+                };
+                compressed
+                    .map(NodeValue::Bytes)
+                    .map_err(|e| compression_error(e.to_string()))
+            }
+        })
+        .await
+        .map_err(|e| compression_error(format!("Compression failed: {}", e)))??;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+// Returns whether zstd is used and the level: 0-9 for gzip (default 6) and
+// 1-22 for zstd (default 3)
+fn level(config: &serde_json::Value) -> Result<(bool, i64), AppError> {
+    let (zstd, range, default) = match config.get("format").and_then(|v| v.as_str()) {
+        None | Some("gzip") => (false, 0..=9, 6),
+        Some("zstd") => (true, 1..=22, 3),
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "Unknown compression format '{}' (expected gzip or zstd)",
+                other
+            )))
+        }
+    };
+    match config_int(config, "level")? {
+        Some(level) if !range.contains(&level) => Err(AppError::Validation(format!(
+            "Compression level must be between {} and {}",
+            range.start(),
+            range.end()
+        ))),
+        level => Ok((zstd, level.unwrap_or(default))),
+    }
+}
+
+pub(super) fn read_limited(reader: impl Read, what: &str) -> Result<Vec<u8>, AppError> {
+    let mut bytes = Vec::new();
+    reader
+        .take(MAX_DECOMPRESSED_SIZE + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| compression_error(format!("Invalid {} data: {}", what, e)))?;
+    if bytes.len() as u64 > MAX_DECOMPRESSED_SIZE {
+        return Err(compression_error(format!(
+            "Decompressed data exceeds {} MB",
+            MAX_DECOMPRESSED_SIZE / 1024 / 1024
+        )));
+    }
+    Ok(bytes)
+}
+
+// Unpacked content is text when it is valid UTF-8 and bytes otherwise,
+// unless the `output` setting asks for one of them
+pub(super) fn bytes_value(
+    bytes: Vec<u8>,
+    config: &serde_json::Value,
+) -> Result<NodeValue, AppError> {
+    match config.get("output").and_then(|v| v.as_str()) {
+        Some("bytes") => Ok(NodeValue::Bytes(bytes)),
+        Some("text") => String::from_utf8(bytes)
+            .map(NodeValue::String)
+            .map_err(|_| {
+                compression_error(
+                    "Unpacked data is not UTF-8 text; set the output to bytes".to_string(),
+                )
+            }),
+        _ => Ok(match String::from_utf8(bytes) {
+            Ok(text) => NodeValue::String(text),
+            Err(e) => NodeValue::Bytes(e.into_bytes()),
+        }),
+    }
+}
+
+pub(super) fn compression_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(input: NodeValue, config: serde_json::Value) -> Result<NodeValue, AppError> {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), input);
+        let ctx = ExecutionContext::new();
+        let mut result = CompressExecutor.execute(inputs, config, &ctx).await?;
+        Ok(result.remove("output").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_compress_round_trip() {
+        let text = format!("id,name\n{}", "1,widget\n".repeat(1000));
+        for format in ["gzip", "zstd"] {
+            let compressed = run(
+                NodeValue::String(text.clone()),
+                serde_json::json!({ "format": format, "level": 9 }),
+            )
+            .await
+            .unwrap();
+            let NodeValue::Bytes(bytes) = &compressed else {
+                panic!("{} output is not bytes", format);
+            };
+            assert!(bytes.len() < text.len() / 10, "{}", format);
+
+            let config = serde_json::json!({ "format": format, "direction": "decompress" });
+            let restored = run(compressed, config).await.unwrap();
+            assert_eq!(restored.as_string().unwrap(), text, "{}", format);
+        }
+
+        let err = run(
+            NodeValue::String("not gzip".to_string()),
+            serde_json::json!({ "direction": "decompress" }),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid gzip data"));
+        assert!(CompressExecutor
+            .validate_config(&serde_json::json!({ "format": "gzip", "level": 12 }))
+            .is_err());
+    }
+}
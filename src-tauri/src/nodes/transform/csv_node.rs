@@ -0,0 +1,283 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct CsvParseExecutor;
+
+#[async_trait]
+impl NodeExecutor for CsvParseExecutor {
+    fn node_type(&self) -> &'static str {
+        "csvParse"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let input = inputs
+            .get("input")
+            .map(|v| v.coerce_to_string())
+            .unwrap_or_default();
+
+        let has_header = config
+            .get("hasHeader")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let infer_types = config
+            .get("inferTypes")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter(&config)?)
+            .quote(single_byte(&config, "quote", b'"')?)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(input.as_bytes());
+
+        let mut rows = reader.records();
+        let mut columns: Vec<String> = Vec::new();
+        if has_header {
+            if let Some(header) = rows.next() {
+                columns = unique_columns(header.map_err(csv_error)?.iter());
+            }
+        }
+
+        let cell = |s: &str| {
+            if infer_types {
+                NodeValue::infer_from_str(s)
+            } else {
+                NodeValue::String(s.to_string())
+            }
+        };
+
+        let mut records = Vec::new();
+        for row in rows {
+            let row = row.map_err(csv_error)?;
+            if has_header {
+                let mut obj = IndexMap::new();
+                for (i, field) in row.iter().enumerate() {
+                    // Extra fields beyond the header get positional names
+                    if i >= columns.len() {
+                        columns.push(format!("column_{}", i + 1));
+                    }
+                    obj.insert(columns[i].clone(), cell(field));
+                }
+                for column in columns.iter().skip(row.len()) {
+                    obj.insert(column.clone(), NodeValue::Null);
+                }
+                records.push(NodeValue::Object(obj));
+            } else {
+                records.push(NodeValue::Array(row.iter().map(cell).collect()));
+            }
+        }
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(records));
+        outputs.insert(
+            "columns".to_string(),
+            NodeValue::Array(columns.into_iter().map(NodeValue::String).collect()),
+        );
+        Ok(outputs)
+    }
+}
+
+pub struct CsvSerializeExecutor;
+
+#[async_trait]
+impl NodeExecutor for CsvSerializeExecutor {
+    fn node_type(&self) -> &'static str {
+        "csvSerialize"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let rows = match inputs.get("input") {
+            Some(NodeValue::Array(arr)) => arr,
+            _ => {
+                return Err(AppError::NodeExecution {
+                    node_id: String::new(),
+                    message: "CSV Serialize expects an array of objects or arrays".to_string(),
+                });
+            }
+        };
+
+        let include_header = config
+            .get("includeHeader")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        // Only the configured columns are written, in the given order; without
+        // any, the keys of all objects are used in first-seen order
+        let mut columns: Vec<String> = config
+            .get("columns")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(String::from)
+            .collect();
+        if columns.is_empty() {
+            for row in rows {
+                if let NodeValue::Object(obj) = row {
+                    for key in obj.keys() {
+                        if !columns.contains(key) {
+                            columns.push(key.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter(&config)?)
+            .quote(single_byte(&config, "quote", b'"')?)
+            .terminator(csv::Terminator::Any(b'\n'))
+            .flexible(true)
+            .from_writer(Vec::new());
+
+        if include_header && !columns.is_empty() {
+            writer.write_record(&columns).map_err(csv_error)?;
+        }
+        for row in rows {
+            let fields: Vec<String> = match row {
+                NodeValue::Object(obj) => columns
+                    .iter()
+                    .map(|c| obj.get(c).map(|v| v.coerce_to_string()).unwrap_or_default())
+                    .collect(),
+                NodeValue::Array(items) => items.iter().map(|v| v.coerce_to_string()).collect(),
+                other => vec![other.coerce_to_string()],
+            };
+            writer.write_record(&fields).map_err(csv_error)?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Failed to write CSV: {}", e),
+        })?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert(
+            "output".to_string(),
+            NodeValue::String(String::from_utf8_lossy(&bytes).into_owned()),
+        );
+        Ok(outputs)
+    }
+}
+
+fn delimiter(config: &serde_json::Value) -> Result<u8, AppError> {
+    match config.get("delimiter").and_then(|v| v.as_str()) {
+        Some("tab") | Some("\\t") => Ok(b'\t'),
+        _ => single_byte(config, "delimiter", b','),
+    }
+}
+
+fn single_byte(config: &serde_json::Value, key: &str, default: u8) -> Result<u8, AppError> {
+    match config.get(key).and_then(|v| v.as_str()) {
+        None | Some("") => Ok(default),
+        Some(s) if s.len() == 1 => Ok(s.as_bytes()[0]),
+        Some(s) => Err(AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("CSV {} must be a single character, got '{}'", key, s),
+        }),
+    }
+}
+
+// Blank header cells get positional names and duplicates get a numeric
+// suffix, so every column maps to its own key
+fn unique_columns<'a>(header: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for (i, name) in header.enumerate() {
+        let name = name.trim();
+        let base = if name.is_empty() {
+            format!("column_{}", i + 1)
+        } else {
+            name.to_string()
+        };
+        let mut column = base.clone();
+        let mut n = 2;
+        while columns.contains(&column) {
+            column = format!("{}_{}", base, n);
+            n += 1;
+        }
+        columns.push(column);
+    }
+    columns
+}
+
+fn csv_error(e: csv::Error) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message: format!("Invalid CSV: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_csv_parse_quoted_fields_and_types() {
+        let csv = "name,zip,score,active,note\n\"Smith, Ada\",02139,9.5,true,\"said \"\"hi\"\"\"\nAlan,10001,7,false,\n";
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::String(csv.to_string()));
+        let ctx = ExecutionContext::new();
+        let result = CsvParseExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+
+        let NodeValue::Array(rows) = result.get("output").unwrap() else {
+            panic!("Expected array output");
+        };
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0].get("name").unwrap().as_string().unwrap(),
+            "Smith, Ada"
+        );
+        assert!(matches!(rows[0].get("zip"), Some(NodeValue::String(z)) if z == "02139"));
+        assert!(matches!(rows[0].get("score"), Some(NodeValue::Number(n)) if *n == 9.5));
+        assert!(matches!(
+            rows[0].get("active"),
+            Some(NodeValue::Boolean(true))
+        ));
+        assert_eq!(
+            rows[0].get("note").unwrap().as_string().unwrap(),
+            "said \"hi\""
+        );
+        assert!(matches!(rows[1].get("score"), Some(NodeValue::Integer(7))));
+        assert!(matches!(rows[1].get("note"), Some(NodeValue::Null)));
+    }
+
+    #[tokio::test]
+    async fn test_csv_serialize_orders_columns() {
+        let rows = NodeValue::from_json(serde_json::json!([
+            { "b": 1, "a": "x, y" },
+            { "a": "z", "c": true }
+        ]));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), rows);
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "columns": "a, b", "delimiter": "tab" });
+        let result = CsvSerializeExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result.get("output").unwrap().as_string().unwrap(),
+            "a\tb\nx, y\t1\nz\t\n"
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use async_trait::async_trait;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{
+    DateTime, FixedOffset, Months, NaiveDate, NaiveDateTime, SecondsFormat, TimeDelta, TimeZone,
+    Utc,
+};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+// Dates travel between nodes as RFC 3339 text such as
+// `2024-03-01T09:30:00Z` or `2024-03-01T10:30:00+01:00`, which JSON, CSV
+// and the expression language all handle as plain strings
+pub struct DateTimeExecutor;
+
+#[async_trait]
+impl NodeExecutor for DateTimeExecutor {
+    fn node_type(&self) -> &'static str {
+        "dateTime"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        Operation::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let operation = Operation::from_config(&config)?;
+
+        // Arrays of dates are handled element-wise, except by Now and by
+        // Difference, which compares the input with the `other` date
+        let output = match (&operation.kind, inputs.get("input")) {
+            (Kind::Now, _) => operation.output(Utc::now().fixed_offset())?,
+            (Kind::Diff(unit), input) => {
+                let start = operation.parse(inputs.get("other").unwrap_or(&NodeValue::Null))?;
+                let end = operation.parse(input.unwrap_or(&NodeValue::Null))?;
+                diff(end, start, unit)
+            }
+            (_, Some(NodeValue::Array(items))) => NodeValue::Array(
+                items
+                    .iter()
+                    .map(|item| operation.apply(item))
+                    .collect::<Result<_, _>>()?,
+            ),
+            (_, input) => operation.apply(input.unwrap_or(&NodeValue::Null))?,
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+// Parse, Convert and Format all read a date and output it, differing only
+// in the time zone and output format they require
+enum Kind {
+    Now,
+    Parse,
+    Add { months: i32, delta: TimeDelta },
+    Diff(String),
+}
+
+struct Operation {
+    kind: Kind,
+    // Input format: a strftime pattern, `unix` or `unixMs`; empty guesses
+    input_format: String,
+    // Zone for dates written without an offset, and for the output
+    timezone: Option<Tz>,
+    // A strftime pattern, `unix` or `unixMs`; RFC 3339 text when unset
+    output_format: Option<String>,
+}
+
+impl Operation {
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        let text = |key: &str| {
+            config
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .trim()
+        };
+        let timezone = match text("timezone") {
+            "" => None,
+            name => Some(name.parse::<Tz>().map_err(|_| {
+                AppError::Validation(format!(
+                    "Unknown time zone '{}' (use a name such as 'Europe/Paris' or 'UTC')",
+                    name
+                ))
+            })?),
+        };
+        let input_format = text("inputFormat").to_string();
+        if !matches!(input_format.as_str(), "" | "unix" | "unixMs") {
+            check_pattern(&input_format)?;
+        }
+
+        let format = text("format");
+        if !matches!(format, "" | "unix" | "unixMs") {
+            check_pattern(format)?;
+        }
+
+        let operation = text("operation");
+        let kind = match operation {
+            "now" => Kind::Now,
+            "parse" => Kind::Parse,
+            "convert" => {
+                if timezone.is_none() {
+                    return Err(AppError::Validation(
+                        "Converting a date needs a time zone".to_string(),
+                    ));
+                }
+                Kind::Parse
+            }
+            "format" => Kind::Parse,
+            "add" => {
+                let (months, delta) = parse_duration(text("amount"))?;
+                Kind::Add { months, delta }
+            }
+            "diff" => match text("unit") {
+                "" => Kind::Diff("seconds".to_string()),
+                unit @ ("milliseconds" | "seconds" | "minutes" | "hours" | "days" | "weeks") => {
+                    Kind::Diff(unit.to_string())
+                }
+                other => {
+                    return Err(AppError::Validation(format!(
+                        "Unknown time unit '{}'",
+                        other
+                    )))
+                }
+            },
+            other => {
+                return Err(AppError::Validation(format!(
+                    "Unknown Date & Time operation '{}'",
+                    other
+                )))
+            }
+        };
+        // Only Now and Format change the output format; the others keep
+        // RFC 3339 so that later date nodes can read their output
+        let output_format = match operation {
+            "format" | "now" if !format.is_empty() => Some(format.to_string()),
+            "format" => Some("%Y-%m-%d %H:%M:%S".to_string()),
+            _ => None,
+        };
+        Ok(Operation {
+            kind,
+            input_format,
+            timezone,
+            output_format,
+        })
+    }
+
+    fn apply(&self, value: &NodeValue) -> Result<NodeValue, AppError> {
+        let date = self.parse(value)?;
+        match &self.kind {
+            Kind::Add { months, delta } => {
+                let shifted = if *months >= 0 {
+                    date.checked_add_months(Months::new(months.unsigned_abs()))
+                } else {
+                    date.checked_sub_months(Months::new(months.unsigned_abs()))
+                }
+                .and_then(|d| d.checked_add_signed(*delta))
+                .ok_or_else(|| date_error("Date is out of range after adding".to_string()))?;
+                self.output(shifted)
+            }
+            _ => self.output(date),
+        }
+    }
+
+    fn output(&self, date: DateTime<FixedOffset>) -> Result<NodeValue, AppError> {
+        let date = match self.timezone {
+            Some(tz) => date.with_timezone(&tz).fixed_offset(),
+            None => date,
+        };
+        let Some(pattern) = &self.output_format else {
+            return Ok(NodeValue::String(
+                date.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            ));
+        };
+        Ok(match pattern.as_str() {
+            "unix" => NodeValue::Integer(date.timestamp()),
+            "unixMs" => NodeValue::Integer(date.timestamp_millis()),
+            pattern => {
+                let mut formatted = String::new();
+                write!(formatted, "{}", date.format(pattern))
+                    .map_err(|_| date_error(format!("Cannot format date with '{}'", pattern)))?;
+                NodeValue::String(formatted)
+            }
+        })
+    }
+
+    fn parse(&self, value: &NodeValue) -> Result<DateTime<FixedOffset>, AppError> {
+        // `f64` parsing accepts "NaN" and "inf", which would otherwise land on 1970
+        let from_unix = |n: f64, millis: bool| {
+            if !n.is_finite() {
+                return Err(date_error(format!(
+                    "Cannot read '{}' as a date: expected a finite timestamp",
+                    n
+                )));
+            }
+            let millis = if millis { n } else { n * 1000.0 };
+            DateTime::from_timestamp_millis(millis.round() as i64)
+                .map(|d| d.fixed_offset())
+                .ok_or_else(|| date_error(format!("Timestamp {} is out of range", n)))
+        };
+        let text = match value {
+            NodeValue::Integer(_) | NodeValue::Number(_) => {
+                let n = value.as_number().unwrap_or(0.0);
+                return from_unix(n, self.input_format == "unixMs");
+            }
+            NodeValue::String(s) => s.trim(),
+            NodeValue::Null => return Err(date_error("No date provided".to_string())),
+            other => {
+                return Err(date_error(format!(
+                    "Expected a date, got '{}'",
+                    other.coerce_to_string()
+                )))
+            }
+        };
+        let invalid = || {
+            let expected = match self.input_format.as_str() {
+                "" => "an RFC 3339 date or a timestamp".to_string(),
+                format => format!("format '{}'", format),
+            };
+            date_error(format!(
+                "Cannot read '{}' as a date: expected {}",
+                text, expected
+            ))
+        };
+
+        match self.input_format.as_str() {
+            "unix" | "unixMs" => {
+                let n: f64 = text.parse().map_err(|_| invalid())?;
+                from_unix(n, self.input_format == "unixMs")
+            }
+            "" => {
+                if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+                    return Ok(date);
+                }
+                if let Ok(date) = DateTime::parse_from_rfc2822(text) {
+                    return Ok(date);
+                }
+                if let Some(n) = text.parse::<f64>().ok().filter(|n| n.is_finite()) {
+                    return from_unix(n, false);
+                }
+                for pattern in [
+                    "%Y-%m-%dT%H:%M:%S%.f",
+                    "%Y-%m-%d %H:%M:%S%.f",
+                    "%Y-%m-%dT%H:%M",
+                    "%Y-%m-%d %H:%M",
+                ] {
+                    if let Ok(naive) = NaiveDateTime::parse_from_str(text, pattern) {
+                        return self.localize(naive);
+                    }
+                }
+                let date = NaiveDate::parse_from_str(text, "%Y-%m-%d").map_err(|_| invalid())?;
+                self.localize(date.and_time(Default::default()))
+            }
+            pattern => {
+                if let Ok(date) = DateTime::parse_from_str(text, pattern) {
+                    return Ok(date);
+                }
+                if let Ok(naive) = NaiveDateTime::parse_from_str(text, pattern) {
+                    return self.localize(naive);
+                }
+                let date = NaiveDate::parse_from_str(text, pattern).map_err(|_| invalid())?;
+                self.localize(date.and_time(Default::default()))
+            }
+        }
+    }
+
+    // A date without an offset is read in the configured zone, or as UTC.
+    // Around a daylight saving change the earlier of two readings wins.
+    fn localize(&self, naive: NaiveDateTime) -> Result<DateTime<FixedOffset>, AppError> {
+        let tz = self.timezone.unwrap_or(Tz::UTC);
+        tz.from_local_datetime(&naive)
+            .earliest()
+            .map(|d| d.fixed_offset())
+            .ok_or_else(|| {
+                date_error(format!(
+                    "{} does not exist in {} (skipped by a daylight saving change)",
+                    naive,
+                    tz.name()
+                ))
+            })
+    }
+}
+
+fn diff(end: DateTime<FixedOffset>, start: DateTime<FixedOffset>, unit: &str) -> NodeValue {
+    let millis = (end - start).num_milliseconds();
+    let per_unit = match unit {
+        "milliseconds" => return NodeValue::Integer(millis),
+        "minutes" => 60_000,
+        "hours" => 3_600_000,
+        "days" => 86_400_000,
+        "weeks" => 604_800_000,
+        _ => 1_000,
+    };
+    if millis % per_unit == 0 {
+        NodeValue::Integer(millis / per_unit)
+    } else {
+        NodeValue::Number(millis as f64 / per_unit as f64)
+    }
+}
+
+// Rejects patterns chrono cannot format up front, rather than failing on
+// the first date
+fn check_pattern(pattern: &str) -> Result<(), AppError> {
+    if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+        return Err(AppError::Validation(format!(
+            "Invalid date format '{}' (see strftime, e.g. '%Y-%m-%d %H:%M')",
+            pattern
+        )));
+    }
+    Ok(())
+}
+
+// Durations are written as amounts with units, such as `1d 12h`, `-90m`
+// or `1y 2mo`: y, mo, w, d, h, m, s and ms. Years and months follow the
+// calendar (the 31st plus a month is clamped to the end of the month).
+fn parse_duration(spec: &str) -> Result<(i32, TimeDelta), AppError> {
+    let invalid = || {
+        AppError::Validation(format!(
+            "Invalid duration '{}' (expected e.g. '1d 12h', '-30m' or '1mo')",
+            spec
+        ))
+    };
+    let (sign, rest) = match spec.trim().strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, spec.trim().trim_start_matches('+')),
+    };
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut months: i64 = 0;
+    let mut millis: i64 = 0;
+    let mut chars = rest.chars().filter(|c| !c.is_whitespace()).peekable();
+    while chars.peek().is_some() {
+        let mut amount = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+            amount.push(c);
+        }
+        let mut unit = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+            unit.push(c);
+        }
+        let amount: i64 = amount.parse().map_err(|_| invalid())?;
+        let (total, per_unit) = match unit.as_str() {
+            "y" => (&mut months, 12),
+            "mo" => (&mut months, 1),
+            "w" => (&mut millis, 604_800_000),
+            "d" => (&mut millis, 86_400_000),
+            "h" => (&mut millis, 3_600_000),
+            "m" => (&mut millis, 60_000),
+            "s" => (&mut millis, 1_000),
+            "ms" => (&mut millis, 1),
+            _ => return Err(invalid()),
+        };
+        *total = amount
+            .checked_mul(per_unit)
+            .and_then(|amount| total.checked_add(amount))
+            .ok_or_else(invalid)?;
+    }
+
+    let months = i32::try_from(months * sign).map_err(|_| invalid())?;
+    let delta = TimeDelta::try_milliseconds(millis * sign).ok_or_else(invalid)?;
+    Ok((months, delta))
+}
+
+fn date_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(
+        input: serde_json::Value,
+        config: serde_json::Value,
+    ) -> Result<NodeValue, AppError> {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::from_json(input));
+        inputs.insert(
+            "other".to_string(),
+            NodeValue::String("2024-03-01T00:00:00Z".to_string()),
+        );
+        let ctx = ExecutionContext::new();
+        let mut result = DateTimeExecutor.execute(inputs, config, &ctx).await?;
+        Ok(result.remove("output").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_parse_format_and_convert() {
+        let parsed = run(
+            serde_json::json!(["31/01/2024 23:30", "01/03/2024 08:00"]),
+            serde_json::json!({
+                "operation": "parse",
+                "inputFormat": "%d/%m/%Y %H:%M",
+                "timezone": "Europe/Paris"
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            parsed.to_json_value(),
+            serde_json::json!(["2024-01-31T23:30:00+01:00", "2024-03-01T08:00:00+01:00"])
+        );
+
+        let converted = run(
+            serde_json::json!("2024-07-01T12:00:00Z"),
+            serde_json::json!({ "operation": "convert", "timezone": "America/New_York" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(converted.as_string().unwrap(), "2024-07-01T08:00:00-04:00");
+
+        let formatted = run(
+            serde_json::json!(1709251200),
+            serde_json::json!({ "operation": "format", "format": "report-%Y%m%d.csv" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(formatted.as_string().unwrap(), "report-20240301.csv");
+
+        assert!(DateTimeExecutor
+            .validate_config(
+                &serde_json::json!({ "operation": "convert", "timezone": "Mars/Base" })
+            )
+            .is_err());
+        assert!(run(
+            serde_json::json!("yesterday"),
+            serde_json::json!({ "operation": "parse" })
+        )
+        .await
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_add_and_diff() {
+        let shifted = run(
+            serde_json::json!("2024-01-31"),
+            serde_json::json!({ "operation": "add", "amount": "1mo 1d 2h" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(shifted.as_string().unwrap(), "2024-03-01T02:00:00Z");
+
+        let earlier = run(
+            serde_json::json!("2024-03-01T12:00:00+02:00"),
+            serde_json::json!({ "operation": "add", "amount": "-90m" }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(earlier.as_string().unwrap(), "2024-03-01T10:30:00+02:00");
+
+        let hours = run(
+            serde_json::json!("2024-03-02T06:00:00Z"),
+            serde_json::json!({ "operation": "diff", "unit": "hours" }),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(hours, NodeValue::Integer(30)));
+        let days = run(
+            serde_json::json!("2024-03-02T06:00:00Z"),
+            serde_json::json!({ "operation": "diff", "unit": "days" }),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(days, NodeValue::Number(d) if d == 1.25));
+
+        for amount in ["9223372036854775807ms 1ms", "9223372036854775807mo 1y"] {
+            let err = parse_duration(amount).unwrap_err();
+            assert!(err.to_string().contains("Invalid duration"), "{}", amount);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_finite_timestamps_are_rejected() {
+        for (text, input_format) in [
+            ("NaN", ""),
+            ("inf", ""),
+            ("NaN", "unix"),
+            ("-inf", "unixMs"),
+        ] {
+            let err = run(
+                serde_json::json!(text),
+                serde_json::json!({ "operation": "parse", "inputFormat": input_format }),
+            )
+            .await
+            .unwrap_err();
+            assert!(err.to_string().contains("Cannot read"), "{} {}", text, err);
+        }
+
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::Number(f64::NAN));
+        let ctx = ExecutionContext::new();
+        let err = DateTimeExecutor
+            .execute(inputs, serde_json::json!({ "operation": "parse" }), &ctx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Cannot read 'NaN' as a date"));
+    }
+}
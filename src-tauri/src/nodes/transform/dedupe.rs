@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use super::fields::{array_input, config_path, field_value, ValueSet};
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct DedupeExecutor;
+
+#[async_trait]
+impl NodeExecutor for DedupeExecutor {
+    fn node_type(&self) -> &'static str {
+        "dedupe"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        config_path(config, "key").map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Dedupe")?;
+        let path = config_path(&config, "key")?;
+        let keep_last = config.get("keep").and_then(|v| v.as_str()) == Some("last");
+
+        // Keeping the last occurrence is keeping the first one of the
+        // reversed array, in the original order
+        let mut seen = ValueSet::default();
+        let mut unique = Vec::new();
+        let ordered: Box<dyn Iterator<Item = &NodeValue>> = if keep_last {
+            Box::new(items.iter().rev())
+        } else {
+            Box::new(items.iter())
+        };
+        for item in ordered {
+            if seen.insert(&field_value(item, path.as_ref())?) {
+                unique.push(item.clone());
+            }
+        }
+        if keep_last {
+            unique.reverse();
+        }
+
+        let removed = items.len() - unique.len();
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(unique));
+        outputs.insert("removed".to_string(), NodeValue::Integer(removed as i64));
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dedupe_by_key_keeps_last() {
+        let users = NodeValue::from_json(serde_json::json!([
+            { "id": 1, "v": "a" },
+            { "id": 2, "v": "b" },
+            { "id": 1.0, "v": "c" }
+        ]));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), users);
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "key": "id", "keep": "last" });
+        let result = DedupeExecutor.execute(inputs, config, &ctx).await.unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!([{ "id": 2, "v": "b" }, { "id": 1.0, "v": "c" }])
+        );
+        assert!(matches!(result["removed"], NodeValue::Integer(1)));
+    }
+}
@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine as _;
+use percent_encoding::{percent_decode_str, percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+// Same characters as JavaScript's `encodeURIComponent` leaves alone
+const URL_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+pub struct EncodeExecutor;
+
+#[async_trait]
+impl NodeExecutor for EncodeExecutor {
+    fn node_type(&self) -> &'static str {
+        "encode"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        Codec::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let codec = Codec::from_config(&config)?;
+        let decode = config.get("direction").and_then(|v| v.as_str()) == Some("decode");
+        let as_bytes = config.get("output").and_then(|v| v.as_str()) == Some("bytes");
+
+        // Text is encoded as UTF-8; bytes (from a binary HTTP response or
+        // File Read) are encoded as they are
+        let input = inputs.get("input").cloned().unwrap_or_default();
+        let output = if decode {
+            let text = input.coerce_to_string();
+            let bytes = codec.decode(text.trim())?;
+            if as_bytes {
+                NodeValue::Bytes(bytes)
+            } else {
+                NodeValue::String(String::from_utf8(bytes).map_err(|_| {
+                    encoding_error(
+                        "Decoded data is not UTF-8 text; set the output to bytes".to_string(),
+                    )
+                })?)
+            }
+        } else {
+            NodeValue::String(codec.encode(&input.coerce_to_bytes()))
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+enum Codec {
+    Base64,
+    Base64Url,
+    Hex,
+    Url,
+}
+
+impl Codec {
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        match config
+            .get("encoding")
+            .and_then(|v| v.as_str())
+            .unwrap_or("base64")
+        {
+            "base64" => Ok(Codec::Base64),
+            "base64url" => Ok(Codec::Base64Url),
+            "hex" => Ok(Codec::Hex),
+            "url" => Ok(Codec::Url),
+            other => Err(AppError::Validation(format!(
+                "Unknown encoding '{}' (expected base64, base64url, hex or url)",
+                other
+            ))),
+        }
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Codec::Base64 => STANDARD.encode(bytes),
+            Codec::Base64Url => URL_SAFE_NO_PAD.encode(bytes),
+            Codec::Hex => hex::encode(bytes),
+            Codec::Url => percent_encode(bytes, URL_COMPONENT).to_string(),
+        }
+    }
+
+    fn decode(&self, text: &str) -> Result<Vec<u8>, AppError> {
+        match self {
+            Codec::Base64 => STANDARD
+                .decode(text)
+                .map_err(|e| encoding_error(format!("Invalid base64: {}", e))),
+            // Padding is optional in URL-safe base64, so it is accepted either way
+            Codec::Base64Url => URL_SAFE_NO_PAD
+                .decode(text.trim_end_matches('='))
+                .map_err(|e| encoding_error(format!("Invalid base64url: {}", e))),
+            Codec::Hex => {
+                hex::decode(text).map_err(|e| encoding_error(format!("Invalid hex: {}", e)))
+            }
+            Codec::Url => Ok(percent_decode_str(text).collect()),
+        }
+    }
+}
+
+fn encoding_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(input: NodeValue, config: serde_json::Value) -> Result<NodeValue, AppError> {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), input);
+        let ctx = ExecutionContext::new();
+        let mut result = EncodeExecutor.execute(inputs, config, &ctx).await?;
+        Ok(result.remove("output").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_encode_and_decode() {
+        let text = NodeValue::String("héllo wörld?&".to_string());
+        let cases = [
+            ("base64", "aMOpbGxvIHfDtnJsZD8m"),
+            ("base64url", "aMOpbGxvIHfDtnJsZD8m"),
+            ("hex", "68c3a96c6c6f2077c3b6726c643f26"),
+            ("url", "h%C3%A9llo%20w%C3%B6rld%3F%26"),
+        ];
+        for (encoding, expected) in cases {
+            let encoded = run(text.clone(), serde_json::json!({ "encoding": encoding }))
+                .await
+                .unwrap();
+            assert_eq!(encoded.as_string().unwrap(), expected, "{}", encoding);
+            let decoded = run(
+                encoded,
+                serde_json::json!({ "encoding": encoding, "direction": "decode" }),
+            )
+            .await
+            .unwrap();
+            assert_eq!(
+                decoded.as_string().unwrap(),
+                "héllo wörld?&",
+                "{}",
+                encoding
+            );
+        }
+
+        let bytes = run(
+            NodeValue::String("AP8=".to_string()),
+            serde_json::json!({ "direction": "decode", "output": "bytes" }),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(bytes, NodeValue::Bytes(ref b) if *b == vec![0, 255]));
+        for (encoding, expected) in [("base64url", "AP8"), ("url", "%00%FF")] {
+            let config = serde_json::json!({ "encoding": encoding });
+            let encoded = run(bytes.clone(), config).await.unwrap();
+            assert_eq!(encoded.as_string().unwrap(), expected, "{}", encoding);
+        }
+
+        let err = run(
+            NodeValue::String("AP8=".to_string()),
+            serde_json::json!({ "direction": "decode" }),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("not UTF-8"));
+    }
+}
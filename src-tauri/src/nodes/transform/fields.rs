@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use crate::engine::expr::values_equal;
+use crate::engine::jsonpath::JsonPath;
+use crate::error::AppError;
+use crate::types::NodeValue;
+
+// Reads a field path such as `user.name` or `tags[0]` from the node config.
+// An empty path means the item itself.
+pub fn config_path(config: &serde_json::Value, key: &str) -> Result<Option<JsonPath>, AppError> {
+    match config.get(key).and_then(|v| v.as_str()).map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(path) => JsonPath::parse(path).map(Some),
+    }
+}
+
+// The first value at `path` within `item`, or null when there is none
+pub fn field_value(item: &NodeValue, path: Option<&JsonPath>) -> Result<NodeValue, AppError> {
+    let Some(path) = path else {
+        return Ok(item.clone());
+    };
+    Ok(path
+        .select(item)?
+        .first()
+        .map(|v| (*v).clone())
+        .unwrap_or_default())
+}
+
+// A set of values under expression-language equality (so `1` and `1.0` are
+// the same). Values are bucketed by their string form to keep large arrays
+// fast.
+#[derive(Default)]
+pub struct ValueSet {
+    buckets: HashMap<String, Vec<NodeValue>>,
+}
+
+impl ValueSet {
+    // Returns false if an equal value was already present
+    pub fn insert(&mut self, value: &NodeValue) -> bool {
+        let bucket = self.buckets.entry(value.coerce_to_string()).or_default();
+        if bucket.iter().any(|v| values_equal(v, value)) {
+            return false;
+        }
+        bucket.push(value.clone());
+        true
+    }
+}
+
+// Groups members by key under the same equality as `ValueSet`, keeping the
+// groups in order of first appearance
+pub struct ValueGroups<T> {
+    buckets: HashMap<String, Vec<usize>>,
+    groups: Vec<(NodeValue, Vec<T>)>,
+}
+
+impl<T> Default for ValueGroups<T> {
+    fn default() -> Self {
+        Self {
+            buckets: HashMap::new(),
+            groups: Vec::new(),
+        }
+    }
+}
+
+impl<T> ValueGroups<T> {
+    pub fn push(&mut self, key: NodeValue, member: T) {
+        let bucket = self.buckets.entry(key.coerce_to_string()).or_default();
+        match bucket
+            .iter()
+            .find(|&&i| values_equal(&self.groups[i].0, &key))
+        {
+            Some(&i) => self.groups[i].1.push(member),
+            None => {
+                bucket.push(self.groups.len());
+                self.groups.push((key, vec![member]));
+            }
+        }
+    }
+
+    pub fn into_groups(self) -> Vec<(NodeValue, Vec<T>)> {
+        self.groups
+    }
+}
+
+// Splits a config list on commas and newlines, except inside brackets,
+// parentheses and quotes
+pub fn split_list(spec: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in spec.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, ',' | '\n') if depth == 0 => {
+                parts.push(&spec[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&spec[start..]);
+    parts
+}
+
+pub fn array_input<'a>(
+    inputs: &'a HashMap<String, NodeValue>,
+    node: &str,
+) -> Result<&'a Vec<NodeValue>, AppError> {
+    match inputs.get("input") {
+        Some(NodeValue::Array(items)) => Ok(items),
+        _ => Err(AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("{} expects an array input", node),
+        }),
+    }
+}
+
+// Reads a whole number from the node config, written either as a number or
+// as text (number widgets may store an empty string). Missing or empty
+// means None.
+pub fn config_int(config: &serde_json::Value, key: &str) -> Result<Option<i64>, AppError> {
+    let invalid = |value: &dyn std::fmt::Display| {
+        AppError::Validation(format!("'{}' must be a whole number, got '{}'", key, value))
+    };
+    match config.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => n.as_i64().map(Some).ok_or_else(|| invalid(n)),
+        Some(serde_json::Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => s.trim().parse().map(Some).map_err(|_| invalid(s)),
+        Some(other) => Err(invalid(other)),
+    }
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
+use crate::engine::expr::Expression;
 use crate::error::AppError;
 use crate::nodes::NodeExecutor;
 use crate::types::NodeValue;
@@ -14,14 +15,39 @@ impl NodeExecutor for FilterExecutor {
         "filter"
     }
 
+    fn accepts_streams(&self) -> bool {
+        true
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        condition(config).map(|_| ())
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
         config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let field = config
+            .get("field")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let condition = condition(&config)?;
+
         let input = match inputs.get("input") {
             Some(NodeValue::Array(arr)) => arr.clone(),
+            // Record streams are filtered lazily, one record at a time
+            Some(NodeValue::Stream(stream)) if !stream.is_text() => {
+                let filtered = stream.map_records("filter", move |item, index| {
+                    Ok(keep(&item, index, &field, condition.as_ref())?.then_some(item))
+                });
+                let mut outputs = HashMap::new();
+                outputs.insert("output".to_string(), NodeValue::Stream(filtered));
+                return Ok(outputs);
+            }
             _ => {
                 return Err(AppError::NodeExecution {
                     node_id: String::new(),
@@ -30,29 +56,12 @@ impl NodeExecutor for FilterExecutor {
             }
         };
 
-        let field = config
-            .get("field")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-
-        // Simple filtering: remove nulls, or filter by field existence
-        let filtered: Vec<NodeValue> = if field.is_empty() {
-            input
-                .into_iter()
-                .filter(|v| !matches!(v, NodeValue::Null))
-                .collect()
-        } else {
-            input
-                .into_iter()
-                .filter(|v| {
-                    if let NodeValue::Object(obj) = v {
-                        obj.contains_key(field)
-                    } else {
-                        false
-                    }
-                })
-                .collect()
-        };
+        let mut filtered = Vec::with_capacity(input.len());
+        for (index, item) in input.into_iter().enumerate() {
+            if keep(&item, index, &field, condition.as_ref())? {
+                filtered.push(item);
+            }
+        }
 
         let mut outputs = HashMap::new();
         outputs.insert("output".to_string(), NodeValue::Array(filtered));
@@ -60,6 +69,35 @@ impl NodeExecutor for FilterExecutor {
     }
 }
 
+fn condition(config: &serde_json::Value) -> Result<Option<Expression>, AppError> {
+    config
+        .get("condition")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(Expression::parse)
+        .transpose()
+}
+
+fn keep(
+    item: &NodeValue,
+    index: usize,
+    field: &str,
+    condition: Option<&Expression>,
+) -> Result<bool, AppError> {
+    // Field existence check applies on top of the condition
+    if !field.is_empty() && !matches!(item, NodeValue::Object(obj) if obj.contains_key(field)) {
+        return Ok(false);
+    }
+    match condition {
+        Some(expr) => {
+            expr.evaluate_bool(&[("item", item), ("index", &NodeValue::Integer(index as i64))])
+        }
+        // Without a condition, drop nulls unless filtering by field
+        None => Ok(!field.is_empty() || !matches!(item, NodeValue::Null)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,4 +125,29 @@ mod tests {
             panic!("Expected array output");
         }
     }
+
+    #[tokio::test]
+    async fn test_filter_condition_expression() {
+        let executor = FilterExecutor;
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::Array(vec![
+                NodeValue::from_json(serde_json::json!({ "name": "a", "score": 3 })),
+                NodeValue::from_json(serde_json::json!({ "name": "b", "score": 9 })),
+                NodeValue::from_json(serde_json::json!({ "name": "c" })),
+            ]),
+        );
+        let config = serde_json::json!({ "condition": "item.score > 5 || item.name == 'c'" });
+        assert!(executor
+            .validate_config(&serde_json::json!({ "condition": "item.score >" }))
+            .is_err());
+        let ctx = ExecutionContext::new();
+        let result = executor.execute(inputs, config, &ctx).await.unwrap();
+        if let NodeValue::Array(arr) = result.get("output").unwrap() {
+            assert_eq!(arr.len(), 2);
+        } else {
+            panic!("Expected array output");
+        }
+    }
 }
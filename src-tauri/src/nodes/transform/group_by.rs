@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+use super::fields::{array_input, config_path, field_value, ValueGroups};
+use crate::engine::context::ExecutionContext;
+use crate::engine::jsonpath::JsonPath;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct GroupByExecutor;
+
+#[async_trait]
+impl NodeExecutor for GroupByExecutor {
+    fn node_type(&self) -> &'static str {
+        "groupBy"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        key_path(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Group By")?;
+        let path = key_path(&config)?;
+
+        // Groups keep the order in which their key first appears
+        let mut groups = ValueGroups::default();
+        for item in items {
+            groups.push(field_value(item, Some(&path))?, item.clone());
+        }
+
+        // `output` is keyed by the key's text, so keys such as `1` and "1"
+        // are separate groups but share one entry there
+        let mut by_key: IndexMap<String, Vec<NodeValue>> = IndexMap::new();
+        let groups = groups.into_groups();
+        let mut list = Vec::with_capacity(groups.len());
+        for (key, members) in groups {
+            by_key
+                .entry(key.coerce_to_string())
+                .or_default()
+                .extend(members.iter().cloned());
+            let mut group = IndexMap::new();
+            group.insert("key".to_string(), key);
+            group.insert("items".to_string(), NodeValue::Array(members));
+            list.push(NodeValue::Object(group));
+        }
+        let by_key = by_key
+            .into_iter()
+            .map(|(name, members)| (name, NodeValue::Array(members)))
+            .collect();
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Object(by_key));
+        outputs.insert("groups".to_string(), NodeValue::Array(list));
+        Ok(outputs)
+    }
+}
+
+fn key_path(config: &serde_json::Value) -> Result<JsonPath, AppError> {
+    config_path(config, "key")?
+        .ok_or_else(|| AppError::Validation("Group By needs a key field".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_group_by_nested_key() {
+        let orders = NodeValue::from_json(serde_json::json!([
+            { "id": 1, "customer": { "country": "NL" } },
+            { "id": 2, "customer": { "country": "US" } },
+            { "id": 3, "customer": { "country": "NL" } }
+        ]));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), orders);
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "key": "customer.country" });
+        let result = GroupByExecutor.execute(inputs, config, &ctx).await.unwrap();
+
+        let NodeValue::Object(by_key) = &result["output"] else {
+            panic!("Expected object output");
+        };
+        assert_eq!(by_key.keys().collect::<Vec<_>>(), ["NL", "US"]);
+        let NodeValue::Array(groups) = &result["groups"] else {
+            panic!("Expected array of groups");
+        };
+        assert_eq!(
+            groups[0].to_json_value()["items"],
+            serde_json::json!([
+                { "id": 1, "customer": { "country": "NL" } },
+                { "id": 3, "customer": { "country": "NL" } }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_group_by_keeps_number_and_text_keys_apart() {
+        let items = NodeValue::from_json(serde_json::json!([
+            { "id": "a", "k": 1 }, { "id": "b", "k": "1" }, { "id": "c", "k": 1 }
+        ]));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), items);
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "key": "k" });
+        let result = GroupByExecutor.execute(inputs, config, &ctx).await.unwrap();
+
+        let groups = result["groups"].to_json_value();
+        assert_eq!(groups[0]["key"], serde_json::json!(1));
+        assert_eq!(groups[0]["items"].as_array().unwrap().len(), 2);
+        assert_eq!(groups[1]["key"], serde_json::json!("1"));
+        assert_eq!(
+            result["output"].to_json_value()["1"],
+            serde_json::json!([{ "id": "a", "k": 1 }, { "id": "c", "k": 1 }, { "id": "b", "k": "1" }])
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct HashExecutor;
+
+#[async_trait]
+impl NodeExecutor for HashExecutor {
+    fn node_type(&self) -> &'static str {
+        "hash"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        algorithm(config)?;
+        digest_encoding(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let data = inputs
+            .get("input")
+            .cloned()
+            .unwrap_or_default()
+            .coerce_to_bytes();
+        let digest = match algorithm(&config)? {
+            "sha256" => Sha256::digest(&data).to_vec(),
+            "sha512" => Sha512::digest(&data).to_vec(),
+            "sha1" => Sha1::digest(&data).to_vec(),
+            "md5" => Md5::digest(&data).to_vec(),
+            _ => blake3::hash(&data).as_bytes().to_vec(),
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), encode_digest(digest, &config)?);
+        Ok(outputs)
+    }
+}
+
+pub struct HmacExecutor;
+
+#[async_trait]
+impl NodeExecutor for HmacExecutor {
+    fn node_type(&self) -> &'static str {
+        "hmac"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        if algorithm(config)? == "blake3" {
+            return Err(AppError::Validation(
+                "HMAC supports sha256, sha512, sha1 and md5".to_string(),
+            ));
+        }
+        digest_encoding(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        self.validate_config(&config)?;
+        let data = inputs
+            .get("input")
+            .cloned()
+            .unwrap_or_default()
+            .coerce_to_bytes();
+
+        // The secret comes from the `key` input so it is not saved with the
+        // workflow; providers that hand out hex or base64 secrets expect the
+        // decoded bytes as the key
+        let key = match inputs.get("key") {
+            Some(NodeValue::Bytes(bytes)) => bytes.clone(),
+            Some(NodeValue::Null) | None => {
+                return Err(hash_error(
+                    "HMAC needs a key on the 'key' input".to_string(),
+                ))
+            }
+            Some(key) => decode_key(&key.coerce_to_string(), &config)?,
+        };
+
+        let signature = match algorithm(&config)? {
+            "sha512" => sign::<Hmac<Sha512>>(&key, &data),
+            "sha1" => sign::<Hmac<Sha1>>(&key, &data),
+            "md5" => sign::<Hmac<Md5>>(&key, &data),
+            _ => sign::<Hmac<Sha256>>(&key, &data),
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), encode_digest(signature, &config)?);
+        Ok(outputs)
+    }
+}
+
+fn decode_key(key: &str, config: &serde_json::Value) -> Result<Vec<u8>, AppError> {
+    match config.get("keyEncoding").and_then(|v| v.as_str()) {
+        Some("hex") => hex::decode(key.trim())
+            .map_err(|e| hash_error(format!("HMAC key is not valid hex: {}", e))),
+        Some("base64") => STANDARD
+            .decode(key.trim())
+            .map_err(|e| hash_error(format!("HMAC key is not valid base64: {}", e))),
+        _ => Ok(key.as_bytes().to_vec()),
+    }
+}
+
+// HMAC accepts keys of any length, so creating the MAC cannot fail
+fn sign<M: Mac + hmac::digest::KeyInit>(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = <M as Mac>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn algorithm(config: &serde_json::Value) -> Result<&str, AppError> {
+    match config
+        .get("algorithm")
+        .and_then(|v| v.as_str())
+        .unwrap_or("sha256")
+    {
+        algorithm @ ("sha256" | "sha512" | "sha1" | "md5" | "blake3") => Ok(algorithm),
+        other => Err(AppError::Validation(format!(
+            "Unknown hash algorithm '{}' (expected sha256, sha512, sha1, md5 or blake3)",
+            other
+        ))),
+    }
+}
+
+// Digests are lowercase hex by default, the form most APIs print and compare
+fn digest_encoding(config: &serde_json::Value) -> Result<&str, AppError> {
+    match config
+        .get("encoding")
+        .and_then(|v| v.as_str())
+        .unwrap_or("hex")
+    {
+        encoding @ ("hex" | "base64" | "bytes") => Ok(encoding),
+        other => Err(AppError::Validation(format!(
+            "Unknown digest encoding '{}' (expected hex, base64 or bytes)",
+            other
+        ))),
+    }
+}
+
+fn encode_digest(digest: Vec<u8>, config: &serde_json::Value) -> Result<NodeValue, AppError> {
+    Ok(match digest_encoding(config)? {
+        "base64" => NodeValue::String(STANDARD.encode(&digest)),
+        "bytes" => NodeValue::Bytes(digest),
+        _ => NodeValue::String(hex::encode(&digest)),
+    })
+}
+
+fn hash_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_hash_algorithms() {
+        let cases = [
+            (
+                "sha256",
+                "hex",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            ("sha1", "hex", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+            ("md5", "base64", "kAFQmDzST7DWlj99KOF/cg=="),
+            (
+                "blake3",
+                "hex",
+                "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85",
+            ),
+        ];
+        let ctx = ExecutionContext::new();
+        for (algorithm, encoding, expected) in cases {
+            let mut inputs = HashMap::new();
+            inputs.insert("input".to_string(), NodeValue::Bytes(b"abc".to_vec()));
+            let config = serde_json::json!({ "algorithm": algorithm, "encoding": encoding });
+            let result = HashExecutor.execute(inputs, config, &ctx).await.unwrap();
+            assert_eq!(
+                result["output"].as_string().unwrap(),
+                expected,
+                "{}",
+                algorithm
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hmac_signature() {
+        // RFC 4231, test case 2
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::String("what do ya want for nothing?".to_string()),
+        );
+        inputs.insert("key".to_string(), NodeValue::String("4a656665".to_string()));
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "algorithm": "sha256", "keyEncoding": "hex" });
+        let result = HmacExecutor
+            .execute(inputs.clone(), config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].as_string().unwrap(),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        inputs.remove("key");
+        let err = HmacExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("needs a key"));
+    }
+}
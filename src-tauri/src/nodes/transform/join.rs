@@ -0,0 +1,261 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+use super::fields::{config_path, field_value};
+use crate::engine::context::ExecutionContext;
+use crate::engine::expr::values_equal;
+use crate::engine::jsonpath::JsonPath;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct JoinExecutor;
+
+#[async_trait]
+impl NodeExecutor for JoinExecutor {
+    fn node_type(&self) -> &'static str {
+        "join"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        key_paths(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let (left_key, right_key) = key_paths(&config)?;
+        let left = records(&inputs, "left", &left_key)?;
+        let right = records(&inputs, "right", &right_key)?;
+        let mode = config
+            .get("mode")
+            .and_then(|v| v.as_str())
+            .unwrap_or("inner");
+        let conflict = Conflict::from_config(&config);
+
+        // Index the right side by the string form of its keys; candidates
+        // are then confirmed with value equality. Null keys never match.
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, (key, _)) in right.iter().enumerate() {
+            if !matches!(key, NodeValue::Null) {
+                index.entry(key.coerce_to_string()).or_default().push(i);
+            }
+        }
+
+        let keep_left = matches!(mode, "left" | "full");
+        let keep_right = matches!(mode, "right" | "full");
+        let mut right_matched = vec![false; right.len()];
+        let mut joined = Vec::new();
+
+        for (key, record) in &left {
+            let mut matched = false;
+            if !matches!(key, NodeValue::Null) {
+                for &i in index.get(&key.coerce_to_string()).into_iter().flatten() {
+                    let (right_key, right_record) = &right[i];
+                    if values_equal(key, right_key) {
+                        joined.push(NodeValue::Object(conflict.merge(record, right_record)?));
+                        right_matched[i] = true;
+                        matched = true;
+                    }
+                }
+            }
+            if !matched && keep_left {
+                joined.push(NodeValue::Object((*record).clone()));
+            }
+        }
+        if keep_right {
+            for ((_, record), matched) in right.iter().zip(&right_matched) {
+                if !matched {
+                    joined.push(NodeValue::Object((*record).clone()));
+                }
+            }
+        }
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::Array(joined));
+        Ok(outputs)
+    }
+}
+
+// How a field present on both sides with different values is resolved
+enum Conflict {
+    PreferLeft,
+    PreferRight,
+    Prefix(String),
+    Error,
+}
+
+impl Conflict {
+    fn from_config(config: &serde_json::Value) -> Self {
+        match config.get("conflict").and_then(|v| v.as_str()) {
+            Some("left") => Conflict::PreferLeft,
+            Some("right") => Conflict::PreferRight,
+            Some("error") => Conflict::Error,
+            _ => Conflict::Prefix(
+                config
+                    .get("rightPrefix")
+                    .and_then(|v| v.as_str())
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or("right_")
+                    .to_string(),
+            ),
+        }
+    }
+
+    // Fields with equal values on both sides, such as the join key, are
+    // not conflicts
+    fn merge(
+        &self,
+        left: &IndexMap<String, NodeValue>,
+        right: &IndexMap<String, NodeValue>,
+    ) -> Result<IndexMap<String, NodeValue>, AppError> {
+        let mut merged = left.clone();
+        for (key, value) in right {
+            match merged.get(key) {
+                None => {
+                    merged.insert(key.clone(), value.clone());
+                }
+                Some(existing) if values_equal(existing, value) => {}
+                Some(_) => match self {
+                    Conflict::PreferLeft => {}
+                    Conflict::PreferRight => {
+                        merged.insert(key.clone(), value.clone());
+                    }
+                    Conflict::Prefix(prefix) => {
+                        merged.insert(format!("{}{}", prefix, key), value.clone());
+                    }
+                    Conflict::Error => {
+                        return Err(AppError::NodeExecution {
+                            node_id: String::new(),
+                            message: format!(
+                                "Join conflict: field '{}' differs between left and right",
+                                key
+                            ),
+                        })
+                    }
+                },
+            }
+        }
+        Ok(merged)
+    }
+}
+
+// The right key defaults to the left one, for tables sharing a column name
+fn key_paths(config: &serde_json::Value) -> Result<(JsonPath, JsonPath), AppError> {
+    let left = config_path(config, "leftKey")?
+        .ok_or_else(|| AppError::Validation("Join needs a left key field".to_string()))?;
+    let right = config_path(config, "rightKey")?.unwrap_or_else(|| left.clone());
+    Ok((left, right))
+}
+
+// A record of one side, paired with its key
+type Keyed<'a> = (NodeValue, &'a IndexMap<String, NodeValue>);
+
+fn records<'a>(
+    inputs: &'a HashMap<String, NodeValue>,
+    port: &str,
+    key: &JsonPath,
+) -> Result<Vec<Keyed<'a>>, AppError> {
+    let error = || AppError::NodeExecution {
+        node_id: String::new(),
+        message: format!("Join expects an array of objects on '{}'", port),
+    };
+    let Some(NodeValue::Array(items)) = inputs.get(port) else {
+        return Err(error());
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            NodeValue::Object(obj) => Ok((field_value(item, Some(key))?, obj)),
+            _ => Err(error()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn join(mode: &str, conflict: &str) -> Result<serde_json::Value, AppError> {
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "left".to_string(),
+            NodeValue::from_json(serde_json::json!([
+                { "id": 1, "name": "Ada", "city": "London" },
+                { "id": 2, "name": "Alan" },
+                { "id": null, "name": "Nobody" }
+            ])),
+        );
+        inputs.insert(
+            "right".to_string(),
+            NodeValue::from_json(serde_json::json!([
+                { "user_id": "1", "city": "Rome" },
+                { "user_id": 1, "city": "Paris", "plan": "pro" },
+                { "user_id": 3, "plan": "free" }
+            ])),
+        );
+        let config = serde_json::json!({
+            "leftKey": "id",
+            "rightKey": "user_id",
+            "mode": mode,
+            "conflict": conflict
+        });
+        let ctx = ExecutionContext::new();
+        let result = JoinExecutor.execute(inputs, config, &ctx).await?;
+        Ok(result["output"].to_json_value())
+    }
+
+    #[tokio::test]
+    async fn test_join_modes_and_conflicts() {
+        // Keys compare by value, so "1" does not match 1
+        assert_eq!(
+            join("inner", "prefix").await.unwrap(),
+            serde_json::json!([{
+                "id": 1, "name": "Ada", "city": "London",
+                "user_id": 1, "right_city": "Paris", "plan": "pro"
+            }])
+        );
+        assert_eq!(join("inner", "right").await.unwrap()[0]["city"], "Paris");
+        assert!(join("inner", "error").await.is_err());
+
+        let full = join("full", "left").await.unwrap();
+        let names: Vec<String> = full
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| {
+                format!(
+                    "{}",
+                    r.get("name").or(r.get("city")).or(r.get("plan")).unwrap()
+                )
+            })
+            .collect();
+        assert_eq!(
+            names,
+            ["\"Ada\"", "\"Alan\"", "\"Nobody\"", "\"Rome\"", "\"free\""]
+        );
+
+        assert_eq!(
+            join("left", "left")
+                .await
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+        assert_eq!(
+            join("right", "left")
+                .await
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+}
@@ -34,15 +34,7 @@ impl NodeExecutor for JsonParseExecutor {
                 message: format!("Invalid JSON: {}", e),
             })?;
 
-        let output = match parsed {
-            serde_json::Value::Object(map) => {
-                NodeValue::Object(map.into_iter().collect())
-            }
-            serde_json::Value::Array(arr) => {
-                NodeValue::Array(arr.into_iter().map(json_to_node_value).collect())
-            }
-            other => json_to_node_value(other),
-        };
+        let output = NodeValue::from_json(parsed);
 
         let mut outputs = HashMap::new();
         outputs.insert("output".to_string(), output);
@@ -50,17 +42,41 @@ impl NodeExecutor for JsonParseExecutor {
     }
 }
 
-fn json_to_node_value(v: serde_json::Value) -> NodeValue {
-    match v {
-        serde_json::Value::Null => NodeValue::Null,
-        serde_json::Value::Bool(b) => NodeValue::Boolean(b),
-        serde_json::Value::Number(n) => NodeValue::Number(n.as_f64().unwrap_or(0.0)),
-        serde_json::Value::String(s) => NodeValue::String(s),
-        serde_json::Value::Array(arr) => {
-            NodeValue::Array(arr.into_iter().map(json_to_node_value).collect())
-        }
-        serde_json::Value::Object(map) => {
-            NodeValue::Object(map.into_iter().map(|(k, v)| (k, v)).collect())
+pub struct JsonSerializeExecutor;
+
+#[async_trait]
+impl NodeExecutor for JsonSerializeExecutor {
+    fn node_type(&self) -> &'static str {
+        "jsonSerialize"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let value = inputs
+            .get("input")
+            .map(|v| v.to_json_value())
+            .unwrap_or(serde_json::Value::Null);
+        let format = config
+            .get("format")
+            .and_then(|v| v.as_str())
+            .unwrap_or("pretty");
+
+        let serialized = if format == "compact" {
+            serde_json::to_string(&value)
+        } else {
+            serde_json::to_string_pretty(&value)
         }
+        .map_err(|e| AppError::NodeExecution {
+            node_id: String::new(),
+            message: format!("Failed to serialize JSON: {}", e),
+        })?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::String(serialized));
+        Ok(outputs)
     }
 }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
+use crate::engine::expr::Expression;
 use crate::error::AppError;
 use crate::nodes::NodeExecutor;
 use crate::types::NodeValue;
@@ -14,14 +15,33 @@ impl NodeExecutor for MapExecutor {
         "map"
     }
 
+    fn accepts_streams(&self) -> bool {
+        true
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        expression(config).map(|_| ())
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
         config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let expr = expression(&config)?;
+
         let input = match inputs.get("input") {
             Some(NodeValue::Array(arr)) => arr.clone(),
+            // Record streams are mapped lazily, one record at a time
+            Some(NodeValue::Stream(stream)) if !stream.is_text() => {
+                let mapped = stream.map_records("map", move |item, index| {
+                    map_item(item, index, expr.as_ref()).map(Some)
+                });
+                let mut outputs = HashMap::new();
+                outputs.insert("output".to_string(), NodeValue::Stream(mapped));
+                return Ok(outputs);
+            }
             _ => {
                 return Err(AppError::NodeExecution {
                     node_id: String::new(),
@@ -30,23 +50,72 @@ impl NodeExecutor for MapExecutor {
             }
         };
 
-        let expression = config
-            .get("expression")
-            .and_then(|v| v.as_str())
-            .unwrap_or("item");
-
-        // Simple map: if expression is "item", pass through. Otherwise, stringify.
-        let mapped: Vec<NodeValue> = if expression == "item" {
-            input
-        } else {
-            input
-                .into_iter()
-                .map(|v| NodeValue::String(v.coerce_to_string()))
-                .collect()
-        };
+        let mapped: Vec<NodeValue> = input
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| map_item(item, index, expr.as_ref()))
+            .collect::<Result<_, _>>()?;
 
         let mut outputs = HashMap::new();
         outputs.insert("output".to_string(), NodeValue::Array(mapped));
         Ok(outputs)
     }
 }
+
+fn expression(config: &serde_json::Value) -> Result<Option<Expression>, AppError> {
+    let source = config
+        .get("expression")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or("item");
+    // "item" is the identity mapping and needs no evaluation
+    match source {
+        "item" => Ok(None),
+        source => Expression::parse(source).map(Some),
+    }
+}
+
+fn map_item(
+    item: NodeValue,
+    index: usize,
+    expr: Option<&Expression>,
+) -> Result<NodeValue, AppError> {
+    match expr {
+        Some(expr) => expr.evaluate(&[
+            ("item", &item),
+            ("index", &NodeValue::Integer(index as i64)),
+        ]),
+        None => Ok(item),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_map_expression() {
+        let executor = MapExecutor;
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::Array(vec![
+                NodeValue::from_json(serde_json::json!({ "name": "ada", "age": 36 })),
+                NodeValue::from_json(serde_json::json!({ "name": "alan", "age": 41 })),
+            ]),
+        );
+        let config = serde_json::json!({ "expression": "upper(item.name) + ':' + (item.age + 1)" });
+        let ctx = ExecutionContext::new();
+        let result = executor.execute(inputs, config, &ctx).await.unwrap();
+        if let NodeValue::Array(arr) = result.get("output").unwrap() {
+            assert_eq!(arr[0].as_string().unwrap(), "ADA:37");
+            assert_eq!(arr[1].as_string().unwrap(), "ALAN:42");
+        } else {
+            panic!("Expected array output");
+        }
+        assert!(executor
+            .validate_config(&serde_json::json!({ "expression": "shout(item)" }))
+            .is_err());
+    }
+}
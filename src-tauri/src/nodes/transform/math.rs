@@ -0,0 +1,253 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use super::fields::config_int;
+use crate::engine::context::ExecutionContext;
+use crate::engine::expr::Expression;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+const VARIABLES: [&str; 3] = ["a", "b", "c"];
+
+pub struct MathExecutor;
+
+#[async_trait]
+impl NodeExecutor for MathExecutor {
+    fn node_type(&self) -> &'static str {
+        "math"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        expression(config)?;
+        NumberFormat::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let expr = expression(&config)?;
+        let format = NumberFormat::from_config(&config)?;
+
+        // Numeric text (say, from a CSV cell or a Text Input) is read as a
+        // number; unconnected inputs are null
+        let mut values = Vec::with_capacity(VARIABLES.len());
+        for name in VARIABLES {
+            let value = match inputs.get(name) {
+                Some(NodeValue::String(s)) => NodeValue::parse_number(s).ok_or_else(|| {
+                    math_error(format!("Input '{}' is not a number: '{}'", name, s))
+                })?,
+                Some(value) => value.clone(),
+                None => NodeValue::Null,
+            };
+            values.push(value);
+        }
+        let vars: Vec<(&str, &NodeValue)> = VARIABLES.iter().copied().zip(&values).collect();
+
+        let result = expr.evaluate(&vars)?;
+        let formatted = match &result {
+            NodeValue::Integer(i) => format.integer(*i),
+            NodeValue::Number(n) => format.number(*n),
+            other => {
+                return Err(math_error(format!(
+                    "Math expression '{}' produced '{}', not a number",
+                    expr.source(),
+                    other.coerce_to_string()
+                )))
+            }
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("result".to_string(), result);
+        outputs.insert("formatted".to_string(), NodeValue::String(formatted));
+        Ok(outputs)
+    }
+}
+
+// The expression is written over the inputs `a`, `b` and `c`, such as
+// `round(a * 1.2, 2)` or `max(a, b) - c`; it defaults to `a`, which just
+// formats the first input
+fn expression(config: &serde_json::Value) -> Result<Expression, AppError> {
+    let source = config
+        .get("expression")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or("a");
+    Expression::parse(source)
+}
+
+struct NumberFormat {
+    decimals: Option<usize>,
+    thousands: String,
+    decimal_point: String,
+    percent: bool,
+    prefix: String,
+    suffix: String,
+}
+
+impl NumberFormat {
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        let text = |key: &str, default: &str| {
+            config
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or(default)
+                .to_string()
+        };
+        let decimals = match config_int(config, "decimals")? {
+            Some(d) if (0..=20).contains(&d) => Some(d as usize),
+            Some(_) => {
+                return Err(AppError::Validation(
+                    "Decimals must be between 0 and 20".to_string(),
+                ))
+            }
+            None => None,
+        };
+        let decimal_point = match text("decimalPoint", ".") {
+            point if point.is_empty() => ".".to_string(),
+            point => point,
+        };
+        Ok(NumberFormat {
+            decimals,
+            thousands: text("thousands", ""),
+            decimal_point,
+            percent: config.get("style").and_then(|v| v.as_str()) == Some("percent"),
+            prefix: text("prefix", ""),
+            suffix: text("suffix", ""),
+        })
+    }
+
+    // Integers are formatted exactly, even beyond what a float can hold
+    fn integer(&self, i: i64) -> String {
+        if self.percent {
+            return self.number(i as f64);
+        }
+        let mut digits = i.unsigned_abs().to_string();
+        if let Some(decimals) = self.decimals.filter(|d| *d > 0) {
+            digits = format!("{}.{}", digits, "0".repeat(decimals));
+        }
+        self.finish(i < 0, &digits, "")
+    }
+
+    fn number(&self, n: f64) -> String {
+        let (n, percent_sign) = if self.percent {
+            (n * 100.0, "%")
+        } else {
+            (n, "")
+        };
+        if !n.is_finite() {
+            return n.to_string();
+        }
+        let digits = match self.decimals {
+            Some(decimals) => format!("{:.*}", decimals, n.abs()),
+            None => n.abs().to_string(),
+        };
+        // Rounding can turn a tiny negative number into zero; "-0.00" would
+        // be misleading
+        let negative = n < 0.0 && digits.chars().any(|c| c.is_ascii_digit() && c != '0');
+        self.finish(negative, &digits, percent_sign)
+    }
+
+    // Groups the whole digits and adds the separators, sign, unit and affixes
+    fn finish(&self, negative: bool, digits: &str, unit: &str) -> String {
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+        let mut grouped = String::new();
+        for (i, c) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push_str(&self.thousands);
+            }
+            grouped.push(c);
+        }
+        if let Some(fraction) = fraction {
+            grouped.push_str(&self.decimal_point);
+            grouped.push_str(fraction);
+        }
+        format!(
+            "{}{}{}{}{}",
+            if negative { "-" } else { "" },
+            self.prefix,
+            grouped,
+            unit,
+            self.suffix
+        )
+    }
+}
+
+fn math_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn calculate(
+        a: NodeValue,
+        b: NodeValue,
+        config: serde_json::Value,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let mut inputs = HashMap::new();
+        inputs.insert("a".to_string(), a);
+        inputs.insert("b".to_string(), b);
+        let ctx = ExecutionContext::new();
+        MathExecutor.execute(inputs, config, &ctx).await
+    }
+
+    #[tokio::test]
+    async fn test_math_expression_and_formatting() {
+        let result = calculate(
+            NodeValue::String("1234567".to_string()),
+            NodeValue::Integer(3),
+            serde_json::json!({
+                "expression": "a * b + 0.5",
+                "decimals": 2,
+                "thousands": ",",
+                "prefix": "$"
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(result["result"], NodeValue::Number(n) if n == 3703701.5));
+        assert_eq!(result["formatted"].as_string().unwrap(), "$3,703,701.50");
+
+        let result = calculate(
+            NodeValue::Number(-0.1234),
+            NodeValue::Null,
+            serde_json::json!({ "style": "percent", "decimals": "1", "decimalPoint": "," }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result["formatted"].as_string().unwrap(), "-12,3%");
+
+        let result = calculate(
+            NodeValue::Integer(9_007_199_254_740_993),
+            NodeValue::Integer(-2),
+            serde_json::json!({ "expression": "max(a, b)", "thousands": " " }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            result["formatted"].as_string().unwrap(),
+            "9 007 199 254 740 993"
+        );
+
+        let err = calculate(
+            NodeValue::String("ten".to_string()),
+            NodeValue::Null,
+            serde_json::json!({}),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("Input 'a' is not a number"));
+    }
+}
@@ -14,21 +14,44 @@ impl NodeExecutor for MergeExecutor {
         "merge"
     }
 
+    fn multi_input_ports(&self) -> &'static [&'static str] {
+        &["inputs"]
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
         _config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
-        let a = inputs.get("a").cloned().unwrap_or(NodeValue::Null);
-        let b = inputs.get("b").cloned().unwrap_or(NodeValue::Null);
+        let fan_in = inputs.get("inputs");
 
-        let merged = match (a, b) {
-            (NodeValue::Array(mut a_arr), NodeValue::Array(b_arr)) => {
-                a_arr.extend(b_arr);
-                NodeValue::Array(a_arr)
+        // `a` and `b` default to null unless values arrive through `inputs`
+        let mut values = Vec::new();
+        for port in ["a", "b"] {
+            match inputs.get(port) {
+                Some(value) => values.push(value.clone()),
+                None if fan_in.is_none() => values.push(NodeValue::Null),
+                None => {}
             }
-            (a_val, b_val) => NodeValue::Array(vec![a_val, b_val]),
+        }
+        if let Some(NodeValue::Array(items)) = fan_in {
+            values.extend(items.iter().cloned());
+        }
+
+        // Arrays are concatenated; anything else is collected into a list
+        let merged = if values.iter().all(|v| matches!(v, NodeValue::Array(_))) {
+            NodeValue::Array(
+                values
+                    .into_iter()
+                    .flat_map(|v| match v {
+                        NodeValue::Array(items) => items,
+                        other => vec![other],
+                    })
+                    .collect(),
+            )
+        } else {
+            NodeValue::Array(values)
         };
 
         let mut outputs = HashMap::new();
@@ -36,3 +59,28 @@ impl NodeExecutor for MergeExecutor {
         Ok(outputs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_merge_fan_in_keeps_order() {
+        let mut inputs = HashMap::new();
+        inputs.insert("a".to_string(), NodeValue::Integer(1));
+        inputs.insert(
+            "inputs".to_string(),
+            NodeValue::Array(vec![NodeValue::Integer(2), NodeValue::Integer(3)]),
+        );
+        let ctx = ExecutionContext::new();
+        let result = MergeExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        let merged: Vec<_> = match result.get("output").unwrap() {
+            NodeValue::Array(arr) => arr.iter().filter_map(|v| v.as_integer()).collect(),
+            _ => panic!("Expected array output"),
+        };
+        assert_eq!(merged, vec![1, 2, 3]);
+    }
+}
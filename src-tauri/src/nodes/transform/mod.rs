@@ -5,11 +5,57 @@ mod filter;
 mod map;
 mod merge;
 mod split;
+mod csv_node;
+mod yaml_node;
+mod toml_node;
+mod xml_node;
+mod query;
+mod fields;
+mod sort;
+mod group_by;
+mod aggregate;
+mod dedupe;
+mod join;
+mod object_transform;
+mod array_ops;
+mod reduce;
+mod text_transform;
+mod math;
+mod date_time;
+mod encoding;
+mod hash;
+mod compression;
+mod archive;
 
 pub use text_template::TextTemplateExecutor;
-pub use json_parse::JsonParseExecutor;
+pub use json_parse::{JsonParseExecutor, JsonSerializeExecutor};
 pub use regex_node::RegexExecutor;
 pub use filter::FilterExecutor;
 pub use map::MapExecutor;
 pub use merge::MergeExecutor;
 pub use split::SplitExecutor;
+pub use csv_node::{CsvParseExecutor, CsvSerializeExecutor};
+pub use yaml_node::{YamlParseExecutor, YamlSerializeExecutor};
+pub use toml_node::{TomlParseExecutor, TomlSerializeExecutor};
+pub use xml_node::{XmlParseExecutor, XmlSerializeExecutor};
+pub use query::QueryExecutor;
+pub use sort::SortExecutor;
+pub use group_by::GroupByExecutor;
+pub use aggregate::AggregateExecutor;
+pub use dedupe::DedupeExecutor;
+pub use join::JoinExecutor;
+pub use object_transform::ObjectTransformExecutor;
+pub use array_ops::{
+    ChunkExecutor, FlattenExecutor, LengthExecutor, ReverseExecutor, SliceExecutor, UniqueExecutor,
+    ZipExecutor,
+};
+pub use reduce::ReduceExecutor;
+pub use text_transform::TextTransformExecutor;
+pub use math::MathExecutor;
+pub use date_time::DateTimeExecutor;
+pub use encoding::EncodeExecutor;
+pub use hash::{HashExecutor, HmacExecutor};
+pub use compression::CompressExecutor;
+pub use archive::{ArchiveCreateExecutor, ArchiveExtractExecutor};
+
+pub(crate) use fields::{config_int, split_list};
@@ -0,0 +1,433 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+use super::fields::split_list;
+use crate::engine::context::ExecutionContext;
+use crate::engine::jsonpath::JsonPath;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+type Object = IndexMap<String, NodeValue>;
+
+pub struct ObjectTransformExecutor;
+
+#[async_trait]
+impl NodeExecutor for ObjectTransformExecutor {
+    fn node_type(&self) -> &'static str {
+        "objectTransform"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        Operation::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let operation = Operation::from_config(&config)?;
+
+        // Arrays are transformed item by item, so records from JSON Parse or
+        // CSV Parse can be normalized in one step
+        let output = match inputs.get("input") {
+            Some(item @ NodeValue::Object(obj)) => NodeValue::Object(operation.apply(item, obj)?),
+            Some(NodeValue::Array(items)) => NodeValue::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| match item {
+                        NodeValue::Object(obj) => {
+                            Ok(NodeValue::Object(operation.apply(item, obj)?))
+                        }
+                        other => Err(transform_error(format!(
+                            "Object Transform expects objects, got {} at index {}",
+                            other.coerce_to_string(),
+                            i
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => {
+                return Err(transform_error(
+                    "Object Transform expects an object or an array of objects".to_string(),
+                ))
+            }
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+// Fields use the JSONPath syntax of every other node, e.g. `address.city`
+// or `$['first name']`. Since they are written to, they may only name
+// object members.
+struct Field {
+    query: JsonPath,
+    path: Path,
+}
+
+type Path = Vec<String>;
+
+enum Operation {
+    Pick(Vec<Field>),
+    Omit(Vec<Path>),
+    Rename(Vec<(Path, Path)>),
+    Set(Vec<(Path, NodeValue)>),
+    Flatten { separator: String, arrays: bool },
+    Unflatten { separator: String },
+}
+
+impl Operation {
+    // `fields` lists one entry per line or comma: paths for pick and omit,
+    // `old=new` for rename and `path=value` for set, where the value is
+    // read as JSON if possible (`3`, `true`, `[1, 2]`) and as text otherwise
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        let text = |key: &str| config.get(key).and_then(|v| v.as_str()).unwrap_or("");
+        let operation = text("operation");
+        let entries: Vec<&str> = split_list(text("fields"))
+            .into_iter()
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .collect();
+        let separator = match text("separator") {
+            "" => ".".to_string(),
+            separator => separator.to_string(),
+        };
+
+        let needs_fields = matches!(operation, "pick" | "omit" | "rename" | "set");
+        if needs_fields && entries.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Object Transform '{}' needs at least one field",
+                operation
+            )));
+        }
+
+        Ok(match operation {
+            "pick" => Operation::Pick(entries.iter().map(|e| field(e)).collect::<Result<_, _>>()?),
+            "omit" => Operation::Omit(
+                entries
+                    .iter()
+                    .map(|e| field(e).map(|field| field.path))
+                    .collect::<Result<_, _>>()?,
+            ),
+            "rename" => Operation::Rename(
+                entries
+                    .iter()
+                    .map(|entry| {
+                        let (from, to) = assignment(entry)?;
+                        Ok((field(from)?.path, field(to)?.path))
+                    })
+                    .collect::<Result<_, AppError>>()?,
+            ),
+            "set" => Operation::Set(
+                entries
+                    .iter()
+                    .map(|entry| {
+                        let (target, value) = assignment(entry)?;
+                        let value = serde_json::from_str(value)
+                            .map(NodeValue::from_json)
+                            .unwrap_or_else(|_| NodeValue::String(value.to_string()));
+                        Ok((field(target)?.path, value))
+                    })
+                    .collect::<Result<_, AppError>>()?,
+            ),
+            "flatten" => Operation::Flatten {
+                separator,
+                arrays: config
+                    .get("flattenArrays")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false),
+            },
+            "unflatten" => Operation::Unflatten { separator },
+            other => {
+                return Err(AppError::Validation(format!(
+                    "Unknown Object Transform operation '{}'",
+                    other
+                )))
+            }
+        })
+    }
+
+    // `item` is the object `obj` as a value, for reading through JSONPath
+    fn apply(&self, item: &NodeValue, obj: &Object) -> Result<Object, AppError> {
+        Ok(match self {
+            Operation::Pick(fields) => {
+                let mut picked = Object::new();
+                for field in fields {
+                    if let Some(value) = field.query.select(item)?.first() {
+                        set_path(&mut picked, &field.path, (*value).clone());
+                    }
+                }
+                picked
+            }
+            Operation::Omit(paths) => {
+                let mut kept = obj.clone();
+                for path in paths {
+                    remove_path(&mut kept, path);
+                }
+                kept
+            }
+            Operation::Rename(renames) => {
+                let mut renamed = obj.clone();
+                for (from, to) in renames {
+                    rename_path(&mut renamed, from, to);
+                }
+                renamed
+            }
+            Operation::Set(assignments) => {
+                let mut updated = obj.clone();
+                for (path, value) in assignments {
+                    set_path(&mut updated, path, value.clone());
+                }
+                updated
+            }
+            Operation::Flatten { separator, arrays } => {
+                let mut flat = Object::new();
+                for (key, value) in obj {
+                    flatten_into(&mut flat, key.clone(), value, separator, *arrays);
+                }
+                flat
+            }
+            Operation::Unflatten { separator } => {
+                let mut nested = Object::new();
+                for (key, value) in obj {
+                    let path: Path = key.split(separator.as_str()).map(String::from).collect();
+                    set_path(&mut nested, &path, value.clone());
+                }
+                nested
+            }
+        })
+    }
+}
+
+fn field(text: &str) -> Result<Field, AppError> {
+    let query = JsonPath::parse(text.trim())?;
+    let path: Path = match query.member_names() {
+        Some(names) if !names.is_empty() => names.into_iter().map(String::from).collect(),
+        _ => {
+            return Err(AppError::Validation(format!(
+                "Invalid field path '{}': expected object fields such as 'address.city'",
+                text
+            )))
+        }
+    };
+    Ok(Field { query, path })
+}
+
+fn assignment(entry: &str) -> Result<(&str, &str), AppError> {
+    entry
+        .split_once('=')
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .ok_or_else(|| AppError::Validation(format!("Expected 'field=value' but got '{}'", entry)))
+}
+
+fn parent_mut<'a>(obj: &'a mut Object, parents: &[String], create: bool) -> Option<&'a mut Object> {
+    let mut current = obj;
+    for key in parents {
+        if create && !matches!(current.get(key), Some(NodeValue::Object(_))) {
+            current.insert(key.clone(), NodeValue::Object(Object::new()));
+        }
+        match current.get_mut(key) {
+            Some(NodeValue::Object(child)) => current = child,
+            _ => return None,
+        }
+    }
+    Some(current)
+}
+
+// Missing (or non-object) intermediate fields are replaced by objects
+fn set_path(obj: &mut Object, path: &[String], value: NodeValue) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    if let Some(parent) = parent_mut(obj, parents, true) {
+        parent.insert(last.clone(), value);
+    }
+}
+
+fn remove_path(obj: &mut Object, path: &[String]) -> Option<NodeValue> {
+    let (last, parents) = path.split_last()?;
+    parent_mut(obj, parents, false)?.shift_remove(last)
+}
+
+// A rename within the same object keeps the field in its position
+fn rename_path(obj: &mut Object, from: &[String], to: &[String]) {
+    let (Some((from_last, from_parents)), Some((to_last, to_parents))) =
+        (from.split_last(), to.split_last())
+    else {
+        return;
+    };
+    if from_parents == to_parents {
+        if let Some(parent) = parent_mut(obj, from_parents, false) {
+            if let Some(index) = parent.get_index_of(from_last) {
+                let value = parent.shift_remove_index(index).map(|(_, v)| v);
+                parent.shift_remove(to_last);
+                let index = index.min(parent.len());
+                if let Some(value) = value {
+                    parent.shift_insert(index, to_last.clone(), value);
+                }
+            }
+        }
+        return;
+    }
+    if let Some(value) = remove_path(obj, from) {
+        set_path(obj, to, value);
+    }
+}
+
+fn flatten_into(
+    flat: &mut Object,
+    prefix: String,
+    value: &NodeValue,
+    separator: &str,
+    arrays: bool,
+) {
+    match value {
+        NodeValue::Object(obj) if !obj.is_empty() => {
+            for (key, child) in obj {
+                flatten_into(
+                    flat,
+                    format!("{}{}{}", prefix, separator, key),
+                    child,
+                    separator,
+                    arrays,
+                );
+            }
+        }
+        NodeValue::Array(items) if arrays && !items.is_empty() => {
+            for (i, child) in items.iter().enumerate() {
+                flatten_into(
+                    flat,
+                    format!("{}{}{}", prefix, separator, i),
+                    child,
+                    separator,
+                    arrays,
+                );
+            }
+        }
+        _ => {
+            flat.insert(prefix, value.clone());
+        }
+    }
+}
+
+fn transform_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn transform(config: serde_json::Value) -> serde_json::Value {
+        let user = NodeValue::from_json(serde_json::json!({
+            "id": 7,
+            "name": { "first": "Ada", "last": "Lovelace" },
+            "email": "ada@example.com",
+            "tags": ["math", "code"]
+        }));
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::Array(vec![user]));
+        let ctx = ExecutionContext::new();
+        let result = ObjectTransformExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        result["output"].to_json_value()[0].clone()
+    }
+
+    #[tokio::test]
+    async fn test_object_operations() {
+        let picked = transform(serde_json::json!({
+            "operation": "pick", "fields": "name.first, id"
+        }))
+        .await;
+        assert_eq!(
+            picked,
+            serde_json::json!({ "name": { "first": "Ada" }, "id": 7 })
+        );
+
+        let omitted = transform(serde_json::json!({
+            "operation": "omit", "fields": "email\nname.last"
+        }))
+        .await;
+        assert_eq!(
+            omitted,
+            serde_json::json!({ "id": 7, "name": { "first": "Ada" }, "tags": ["math", "code"] })
+        );
+
+        let renamed = transform(serde_json::json!({
+            "operation": "rename", "fields": "email=contact, name.first=firstName"
+        }))
+        .await;
+        let keys: Vec<&String> = renamed.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["id", "name", "contact", "tags", "firstName"]);
+
+        // Fields are JSONPath, so a key containing a dot can be named
+        let picked = transform(serde_json::json!({
+            "operation": "pick", "fields": "$.name['last'], $['e.mail']"
+        }))
+        .await;
+        assert_eq!(
+            picked,
+            serde_json::json!({ "name": { "last": "Lovelace" } })
+        );
+        let set = transform(serde_json::json!({
+            "operation": "set", "fields": "$['e.mail']=hidden"
+        }))
+        .await;
+        assert_eq!(set["e.mail"], "hidden");
+        let err = ObjectTransformExecutor
+            .validate_config(&serde_json::json!({ "operation": "omit", "fields": "tags[0]" }))
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid field path 'tags[0]'"));
+
+        let set = transform(serde_json::json!({
+            "operation": "set", "fields": "status=active, meta.version=2, tags=[\"x\", \"y\"]"
+        }))
+        .await;
+        assert_eq!(set["status"], "active");
+        assert_eq!(set["meta"], serde_json::json!({ "version": 2 }));
+        assert_eq!(set["tags"], serde_json::json!(["x", "y"]));
+
+        let flat = transform(serde_json::json!({
+            "operation": "flatten", "flattenArrays": true
+        }))
+        .await;
+        assert_eq!(
+            flat,
+            serde_json::json!({
+                "id": 7, "name.first": "Ada", "name.last": "Lovelace",
+                "email": "ada@example.com", "tags.0": "math", "tags.1": "code"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unflatten_round_trip() {
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::from_json(serde_json::json!({ "a_b": 1, "a_c": 2, "d": 3 })),
+        );
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "operation": "unflatten", "separator": "_" });
+        let result = ObjectTransformExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!({ "a": { "b": 1, "c": 2 }, "d": 3 })
+        );
+    }
+}
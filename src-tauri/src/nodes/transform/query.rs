@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::engine::jsonpath::JsonPath;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct QueryExecutor;
+
+#[async_trait]
+impl NodeExecutor for QueryExecutor {
+    fn node_type(&self) -> &'static str {
+        "query"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        parse_query(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let path = parse_query(&config)?;
+        let mode = config
+            .get("mode")
+            .and_then(|v| v.as_str())
+            .unwrap_or("auto");
+
+        // JSON text, such as an HTTP response body, is queried as parsed JSON
+        let input = match inputs.get("input") {
+            Some(NodeValue::String(s)) => serde_json::from_str(s)
+                .map(NodeValue::from_json)
+                .unwrap_or_else(|_| NodeValue::String(s.clone())),
+            Some(value) => value.clone(),
+            None => NodeValue::Null,
+        };
+
+        let matches = path.select(&input)?;
+        let count = matches.len();
+        let single = match mode {
+            "first" => true,
+            "all" => false,
+            _ => path.is_definite(),
+        };
+        let output = if single {
+            matches.first().map(|v| (*v).clone()).unwrap_or_default()
+        } else {
+            NodeValue::Array(matches.into_iter().cloned().collect())
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        outputs.insert("count".to_string(), NodeValue::Integer(count as i64));
+        Ok(outputs)
+    }
+}
+
+fn parse_query(config: &serde_json::Value) -> Result<JsonPath, AppError> {
+    let query = config
+        .get("query")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .unwrap_or("");
+    if query.is_empty() {
+        return Err(AppError::Validation("Query is empty".to_string()));
+    }
+    JsonPath::parse(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_query_json_response() {
+        let body = r#"{"data": {"items": [{"name": "a"}, {"name": "b"}]}}"#;
+        let ctx = ExecutionContext::new();
+
+        let run = |query: &str, mode: &str| {
+            let mut inputs = HashMap::new();
+            inputs.insert("input".to_string(), NodeValue::String(body.to_string()));
+            let config = serde_json::json!({ "query": query, "mode": mode });
+            QueryExecutor.execute(inputs, config, &ctx)
+        };
+
+        let all = run("data.items[*].name", "auto").await.unwrap();
+        assert_eq!(all["output"].to_json_value(), serde_json::json!(["a", "b"]));
+        assert!(matches!(all["count"], NodeValue::Integer(2)));
+
+        let one = run("$.data.items[1].name", "auto").await.unwrap();
+        assert_eq!(one["output"].as_string().unwrap(), "b");
+
+        let first = run("data.items[*].name", "first").await.unwrap();
+        assert_eq!(first["output"].as_string().unwrap(), "a");
+
+        let missing = run("data.total", "auto").await.unwrap();
+        assert!(matches!(missing["output"], NodeValue::Null));
+
+        assert!(QueryExecutor
+            .validate_config(&serde_json::json!({ "query": "data.items[" }))
+            .is_err());
+    }
+}
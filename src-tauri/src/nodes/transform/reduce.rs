@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use super::fields::array_input;
+use crate::engine::context::ExecutionContext;
+use crate::engine::expr::Expression;
+use crate::error::AppError;
+use crate::nodes::code::{check_script, fold_script};
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct ReduceExecutor;
+
+#[async_trait]
+impl NodeExecutor for ReduceExecutor {
+    fn node_type(&self) -> &'static str {
+        "reduce"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        Reducer::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Reduce")?;
+        let reducer = Reducer::from_config(&config)?;
+        let initial = initial_value(&config);
+        let keep_steps = config
+            .get("emitSteps")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let (result, steps) = match reducer {
+            Reducer::Expression(expr) => {
+                let mut acc = initial;
+                let mut steps = Vec::new();
+                for (index, item) in items.iter().enumerate() {
+                    if ctx.cancelled.load(Ordering::Relaxed) {
+                        return Err(AppError::Cancelled);
+                    }
+                    acc = expr.evaluate_owned(
+                        "acc",
+                        acc,
+                        &[("item", item), ("index", &NodeValue::Integer(index as i64))],
+                    )?;
+                    if keep_steps {
+                        steps.push(acc.clone());
+                    }
+                }
+                (acc, steps)
+            }
+            Reducer::Script(code) => {
+                let items = items.clone();
+                let cancelled = ctx.cancelled.clone();
+                tokio::task::spawn_blocking(move || {
+                    fold_script(&code, &initial, &items, keep_steps, move || {
+                        cancelled.load(Ordering::Relaxed)
+                    })
+                })
+                .await
+                .map_err(|e| AppError::NodeExecution {
+                    node_id: String::new(),
+                    message: format!("Script task failed: {}", e),
+                })??
+            }
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), result);
+        if keep_steps {
+            outputs.insert("steps".to_string(), NodeValue::Array(steps));
+        }
+        Ok(outputs)
+    }
+}
+
+// The accumulator is written as an expression over `acc`, `item` and
+// `index`, such as `acc + item.amount` or `set(acc, item.id, item)`, or as a
+// Rhai script for anything more involved
+enum Reducer {
+    Expression(Expression),
+    Script(String),
+}
+
+impl Reducer {
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        let source = config
+            .get("expression")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .unwrap_or("");
+        if source.is_empty() {
+            return Err(AppError::Validation(
+                "Reduce needs an accumulator expression".to_string(),
+            ));
+        }
+        match config.get("language").and_then(|v| v.as_str()) {
+            Some("script") => check_script(source).map(|_| Reducer::Script(source.to_string())),
+            _ => Expression::parse(source).map(Reducer::Expression),
+        }
+    }
+}
+
+// The initial value is read as JSON when possible (`0`, `{}`, `[]`), as
+// text otherwise, and is null when empty
+fn initial_value(config: &serde_json::Value) -> NodeValue {
+    match config.get("initial") {
+        Some(serde_json::Value::String(s)) if s.trim().is_empty() => NodeValue::Null,
+        Some(serde_json::Value::String(s)) => serde_json::from_str(s.trim())
+            .map(NodeValue::from_json)
+            .unwrap_or_else(|_| NodeValue::String(s.clone())),
+        Some(value) => NodeValue::from_json(value.clone()),
+        None => NodeValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn orders() -> HashMap<String, NodeValue> {
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::from_json(serde_json::json!([
+                { "id": "a", "amount": 5 },
+                { "id": "b", "amount": 7 },
+                { "id": "c", "amount": 1.5 }
+            ])),
+        );
+        inputs
+    }
+
+    #[tokio::test]
+    async fn test_reduce_running_total_and_lookup() {
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({
+            "initial": "0",
+            "expression": "acc + item.amount",
+            "emitSteps": true
+        });
+        let result = ReduceExecutor
+            .execute(orders(), config, &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(result["output"], NodeValue::Number(n) if n == 13.5));
+        assert_eq!(
+            result["steps"].to_json_value(),
+            serde_json::json!([5, 12, 13.5])
+        );
+
+        let config = serde_json::json!({
+            "initial": "{}",
+            "expression": "set(acc, item.id, item.amount)"
+        });
+        let result = ReduceExecutor
+            .execute(orders(), config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!({ "a": 5, "b": 7, "c": 1.5 })
+        );
+        assert!(!result.contains_key("steps"));
+    }
+
+    #[tokio::test]
+    async fn test_reduce_script() {
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({
+            "language": "script",
+            "initial": "[]",
+            "expression": "if item.amount > 2 { acc.push(item.id + index); } acc"
+        });
+        let result = ReduceExecutor
+            .execute(orders(), config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["output"].to_json_value(),
+            serde_json::json!(["a0", "b1"])
+        );
+
+        let config = serde_json::json!({ "language": "script", "expression": "acc +" });
+        let err = ReduceExecutor.validate_config(&config).unwrap_err();
+        assert!(err.to_string().contains("Script syntax error"));
+    }
+
+    #[tokio::test]
+    async fn test_reduce_builds_large_lookup_and_stops_when_cancelled() {
+        let items: Vec<NodeValue> = (0..20_000)
+            .map(|i| NodeValue::from_json(serde_json::json!({ "id": format!("k{}", i % 10_000) })))
+            .collect();
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::Array(items));
+        let config = serde_json::json!({
+            "initial": "{}",
+            "expression": "item.id != null ? set(acc, item.id, (acc[item.id] ?? 0) + 1) : acc"
+        });
+
+        let ctx = ExecutionContext::new();
+        let result = ReduceExecutor
+            .execute(inputs.clone(), config.clone(), &ctx)
+            .await
+            .unwrap();
+        let NodeValue::Object(lookup) = &result["output"] else {
+            panic!("expected an object");
+        };
+        assert_eq!(lookup.len(), 10_000);
+        assert!(matches!(lookup["k9999"], NodeValue::Integer(2)));
+
+        ctx.cancelled.store(true, Ordering::Relaxed);
+        let err = ReduceExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Cancelled));
+    }
+}
@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use indexmap::IndexMap;
+use regex::{Regex, RegexBuilder};
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
@@ -14,62 +16,237 @@ impl NodeExecutor for RegexExecutor {
         "regex"
     }
 
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        compile(config).map(|_| ())
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
         config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
-        let input = inputs
-            .get("input")
-            .and_then(|v| v.as_string())
-            .unwrap_or_default();
-
-        let pattern = config
-            .get("pattern")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-
+        let (re, global) = compile(&config)?;
         let mode = config
             .get("mode")
             .and_then(|v| v.as_str())
             .unwrap_or("match");
+        let replacement = config
+            .get("replacement")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
 
-        if pattern.len() > 1000 {
-            return Err(AppError::NodeExecution {
-                node_id: String::new(),
-                message: "Regex pattern too long (max 1000 chars)".to_string(),
-            });
+        // An array of texts is processed item by item with the same compiled
+        // pattern, and every output becomes an array with one entry per item
+        let mut outputs = HashMap::new();
+        match inputs.get("input") {
+            Some(NodeValue::Array(items)) => {
+                let results: Vec<Outcome> = items
+                    .iter()
+                    .map(|item| apply(&re, global, mode, replacement, &item.coerce_to_string()))
+                    .collect();
+                for port in ["matches", "captures", "isMatch", "result", "parts"] {
+                    let values = results.iter().map(|r| r.get(port)).collect();
+                    outputs.insert(port.to_string(), NodeValue::Array(values));
+                }
+            }
+            input => {
+                let input = input.and_then(|v| v.as_string()).unwrap_or_default();
+                let outcome = apply(&re, global, mode, replacement, &input);
+                for port in ["matches", "captures", "isMatch", "result", "parts"] {
+                    outputs.insert(port.to_string(), outcome.get(port));
+                }
+            }
         }
 
-        let re = regex::Regex::new(pattern).map_err(|e| AppError::NodeExecution {
-            node_id: String::new(),
-            message: format!("Invalid regex: {}", e),
-        })?;
+        Ok(outputs)
+    }
+}
+
+// Flags follow JavaScript: `g` for every match (otherwise only the first),
+// `i` ignore case, `m` multiline anchors, `s` dot matches newlines, plus
+// `x` for verbose patterns. Without a flags setting, all matches are used.
+fn compile(config: &serde_json::Value) -> Result<(Regex, bool), AppError> {
+    let pattern = config.get("pattern").and_then(|v| v.as_str()).unwrap_or("");
+    let flags = config.get("flags").and_then(|v| v.as_str()).unwrap_or("g");
 
-        let mut outputs = HashMap::new();
+    if pattern.len() > 1000 {
+        return Err(AppError::Validation(
+            "Regex pattern too long (max 1000 chars)".to_string(),
+        ));
+    }
 
-        match mode {
-            "replace" => {
-                let replacement = config
-                    .get("replacement")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("");
-                let result = re.replace_all(&input, replacement).to_string();
-                outputs.insert("result".to_string(), NodeValue::String(result));
-                outputs.insert("matches".to_string(), NodeValue::Array(vec![]));
+    let mut builder = RegexBuilder::new(pattern);
+    let mut global = false;
+    for flag in flags.chars().filter(|c| !c.is_whitespace() && *c != ',') {
+        match flag {
+            'g' => global = true,
+            'i' => {
+                builder.case_insensitive(true);
             }
-            _ => {
-                // match mode
-                let matches: Vec<NodeValue> = re
-                    .find_iter(&input)
-                    .map(|m| NodeValue::String(m.as_str().to_string()))
-                    .collect();
-                outputs.insert("matches".to_string(), NodeValue::Array(matches));
-                outputs.insert("result".to_string(), NodeValue::String(input));
+            'm' => {
+                builder.multi_line(true);
+            }
+            's' => {
+                builder.dot_matches_new_line(true);
+            }
+            'x' => {
+                builder.ignore_whitespace(true);
+            }
+            other => {
+                return Err(AppError::Validation(format!(
+                    "Unknown regex flag '{}' (expected g, i, m, s or x)",
+                    other
+                )))
             }
         }
+    }
 
-        Ok(outputs)
+    let re = builder
+        .build()
+        .map_err(|e| AppError::Validation(format!("Invalid regex: {}", e)))?;
+    Ok((re, global))
+}
+
+struct Outcome {
+    matches: Vec<NodeValue>,
+    captures: Vec<NodeValue>,
+    result: String,
+    parts: Vec<NodeValue>,
+}
+
+impl Outcome {
+    fn get(&self, port: &str) -> NodeValue {
+        match port {
+            "matches" => NodeValue::Array(self.matches.clone()),
+            "captures" => NodeValue::Array(self.captures.clone()),
+            "isMatch" => NodeValue::Boolean(!self.matches.is_empty()),
+            "result" => NodeValue::String(self.result.clone()),
+            _ => NodeValue::Array(self.parts.clone()),
+        }
+    }
+}
+
+fn apply(re: &Regex, global: bool, mode: &str, replacement: &str, input: &str) -> Outcome {
+    let limit = if global { usize::MAX } else { 1 };
+
+    // Each capture is an object of its groups by number ("0" is the whole
+    // match) and by name; groups that did not take part are null
+    let mut matches = Vec::new();
+    let mut captures = Vec::new();
+    for caps in re.captures_iter(input).take(limit) {
+        matches.push(NodeValue::String(caps[0].to_string()));
+        let mut groups = IndexMap::new();
+        for (i, name) in re.capture_names().enumerate() {
+            let value = caps
+                .get(i)
+                .map(|m| NodeValue::String(m.as_str().to_string()))
+                .unwrap_or_default();
+            if let Some(name) = name {
+                groups.insert(name.to_string(), value.clone());
+            }
+            groups.insert(i.to_string(), value);
+        }
+        captures.push(NodeValue::Object(groups));
+    }
+
+    // `replacen` treats a limit of 0 as "all"
+    let result = match mode {
+        "replace" => re
+            .replacen(input, if global { 0 } else { 1 }, replacement)
+            .into_owned(),
+        _ => input.to_string(),
+    };
+
+    // Without `g`, splitting stops after the first separator
+    let parts = if mode == "split" {
+        let pieces: Box<dyn Iterator<Item = &str>> = if global {
+            Box::new(re.split(input))
+        } else {
+            Box::new(re.splitn(input, 2))
+        };
+        pieces.map(|s| NodeValue::String(s.to_string())).collect()
+    } else {
+        Vec::new()
+    };
+
+    Outcome {
+        matches,
+        captures,
+        result,
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run(input: NodeValue, config: serde_json::Value) -> HashMap<String, NodeValue> {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), input);
+        let ctx = ExecutionContext::new();
+        RegexExecutor.execute(inputs, config, &ctx).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_regex_flags_captures_and_modes() {
+        let text = NodeValue::String("Order A-12, order b-7".to_string());
+        let pattern = r"order (?P<code>[a-z])-(\d+)";
+
+        let result = run(
+            text.clone(),
+            serde_json::json!({ "pattern": pattern, "flags": "gi" }),
+        )
+        .await;
+        assert_eq!(
+            result["matches"].to_json_value(),
+            serde_json::json!(["Order A-12", "order b-7"])
+        );
+        assert_eq!(
+            result["captures"].to_json_value()[1],
+            serde_json::json!({ "0": "order b-7", "code": "b", "1": "b", "2": "7" })
+        );
+        assert!(matches!(result["isMatch"], NodeValue::Boolean(true)));
+
+        // Without `g` only the first match is used, and without `i` case matters
+        let result = run(
+            text.clone(),
+            serde_json::json!({ "pattern": pattern, "flags": "", "mode": "replace", "replacement": "#$2" }),
+        )
+        .await;
+        assert_eq!(result["result"].as_string().unwrap(), "Order A-12, #7");
+
+        let result = run(
+            text,
+            serde_json::json!({ "pattern": r",\s*", "mode": "split" }),
+        )
+        .await;
+        assert_eq!(
+            result["parts"].to_json_value(),
+            serde_json::json!(["Order A-12", "order b-7"])
+        );
+
+        assert!(RegexExecutor
+            .validate_config(&serde_json::json!({ "pattern": "a", "flags": "q" }))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_regex_over_array_items() {
+        let items = NodeValue::from_json(serde_json::json!(["a1\nb2", "none"]));
+        let result = run(
+            items,
+            serde_json::json!({ "pattern": r"^\w\d$", "flags": "gm" }),
+        )
+        .await;
+        assert_eq!(
+            result["matches"].to_json_value(),
+            serde_json::json!([["a1", "b2"], []])
+        );
+        assert_eq!(
+            result["isMatch"].to_json_value(),
+            serde_json::json!([true, false])
+        );
     }
 }
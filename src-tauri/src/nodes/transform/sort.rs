@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::fields::{array_input, config_path, field_value};
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct SortExecutor;
+
+#[async_trait]
+impl NodeExecutor for SortExecutor {
+    fn node_type(&self) -> &'static str {
+        "sort"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        config_path(config, "field").map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let items = array_input(&inputs, "Sort")?;
+        let path = config_path(&config, "field")?;
+        let descending = config.get("order").and_then(|v| v.as_str()) == Some("desc");
+        let compare = config
+            .get("compare")
+            .and_then(|v| v.as_str())
+            .unwrap_or("auto");
+
+        let mut keyed = items
+            .iter()
+            .map(|item| Ok((field_value(item, path.as_ref())?, item)))
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        // Stable, and items without the field go last in either direction
+        keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+            (NodeValue::Null, NodeValue::Null) => Ordering::Equal,
+            (NodeValue::Null, _) => Ordering::Greater,
+            (_, NodeValue::Null) => Ordering::Less,
+            _ => {
+                let ordering = compare_keys(a, b, compare);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        });
+
+        let mut outputs = HashMap::new();
+        outputs.insert(
+            "output".to_string(),
+            NodeValue::Array(keyed.into_iter().map(|(_, item)| item.clone()).collect()),
+        );
+        Ok(outputs)
+    }
+}
+
+// Every mode is a total order, which `sort_by` relies on; NaN sorts after
+// all other numbers
+fn compare_keys(a: &NodeValue, b: &NodeValue, compare: &str) -> Ordering {
+    let as_strings = || a.coerce_to_string().cmp(&b.coerce_to_string());
+    match compare {
+        // Non-numeric values sort after numbers
+        "numeric" => match (a.as_number(), b.as_number()) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => as_strings(),
+        },
+        "string" => as_strings(),
+        "natural" => natural_cmp(&a.coerce_to_string(), &b.coerce_to_string()),
+        _ => rank(a).cmp(&rank(b)).then_with(|| match (a, b) {
+            (NodeValue::Boolean(x), NodeValue::Boolean(y)) => x.cmp(y),
+            (NodeValue::String(x), NodeValue::String(y)) => x.cmp(y),
+            (NodeValue::Integer(_) | NodeValue::Number(_), _) => {
+                let number = |v: &NodeValue| v.as_number().unwrap_or_default();
+                number(a).total_cmp(&number(b))
+            }
+            _ => as_strings(),
+        }),
+    }
+}
+
+// Auto mode orders by type first: null, booleans, numbers, text, then
+// everything else by its text form
+fn rank(value: &NodeValue) -> u8 {
+    match value {
+        NodeValue::Null => 0,
+        NodeValue::Boolean(_) => 1,
+        NodeValue::Integer(_) | NodeValue::Number(_) => 2,
+        NodeValue::String(_) => 3,
+        _ => 4,
+    }
+}
+
+// Digit runs compare by their numeric value and everything else
+// case-insensitively, so "file2" sorts before "File10"
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        let ordering = match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_digits(&mut a);
+                let y = take_digits(&mut b);
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+            }
+            (Some(x), Some(y)) => {
+                a.next();
+                b.next();
+                x.to_lowercase().cmp(y.to_lowercase())
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        digits.push(c);
+    }
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn sort(items: serde_json::Value, config: serde_json::Value) -> serde_json::Value {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::from_json(items));
+        let ctx = ExecutionContext::new();
+        let result = SortExecutor.execute(inputs, config, &ctx).await.unwrap();
+        result["output"].to_json_value()
+    }
+
+    #[tokio::test]
+    async fn test_sort_modes() {
+        let files = serde_json::json!([
+            { "name": "file10.txt", "size": "9" },
+            { "name": "File2.txt", "size": "10" },
+            { "name": "file1.txt" }
+        ]);
+
+        let natural = sort(
+            files.clone(),
+            serde_json::json!({ "field": "name", "compare": "natural" }),
+        )
+        .await;
+        let names: Vec<&str> = natural
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["file1.txt", "File2.txt", "file10.txt"]);
+
+        // Missing sizes stay last even when descending
+        let by_size = sort(
+            files.clone(),
+            serde_json::json!({ "field": "size", "compare": "numeric", "order": "desc" }),
+        )
+        .await;
+        assert_eq!(by_size[0]["size"], "10");
+        assert_eq!(by_size[2]["name"], "file1.txt");
+
+        let as_strings = sort(
+            files,
+            serde_json::json!({ "field": "size", "compare": "string" }),
+        )
+        .await;
+        assert_eq!(as_strings[0]["size"], "10");
+
+        let numbers = sort(serde_json::json!([3, 1.5, 2]), serde_json::json!({})).await;
+        assert_eq!(numbers, serde_json::json!([1.5, 2, 3]));
+
+        // Mixed keys, including a NaN from text, still sort consistently
+        let mixed = serde_json::json!(["b", 10, "NaN", 2.5, "a", true, "9"]);
+        let auto = sort(mixed.clone(), serde_json::json!({})).await;
+        assert_eq!(
+            auto,
+            serde_json::json!([true, 2.5, 10, "9", "NaN", "a", "b"])
+        );
+        let numeric = sort(mixed, serde_json::json!({ "compare": "numeric" })).await;
+        assert_eq!(
+            numeric,
+            serde_json::json!([true, 2.5, "9", 10, "NaN", "a", "b"])
+        );
+    }
+}
@@ -14,28 +14,40 @@ impl NodeExecutor for SplitExecutor {
         "split"
     }
 
+    fn accepts_streams(&self) -> bool {
+        true
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
         config: serde_json::Value,
         _ctx: &ExecutionContext,
     ) -> Result<HashMap<String, NodeValue>, AppError> {
-        let input = inputs
-            .get("input")
-            .and_then(|v| v.as_string())
-            .unwrap_or_default();
-
         let delimiter = config
             .get("delimiter")
             .and_then(|v| v.as_str())
             .unwrap_or(",");
 
+        let mut outputs = HashMap::new();
+        let input = match inputs.get("input") {
+            // Text streams are split record by record as they are read
+            Some(NodeValue::Stream(stream)) if stream.is_text() && !delimiter.is_empty() => {
+                let records = NodeValue::Stream(stream.split(delimiter));
+                outputs.insert("output".to_string(), records);
+                return Ok(outputs);
+            }
+            Some(NodeValue::Stream(stream)) => stream.materialize().await?.as_string(),
+            Some(value) => value.as_string(),
+            None => None,
+        }
+        .unwrap_or_default();
+
         let parts: Vec<NodeValue> = input
             .split(delimiter)
             .map(|s| NodeValue::String(s.trim().to_string()))
             .collect();
 
-        let mut outputs = HashMap::new();
         outputs.insert("output".to_string(), NodeValue::Array(parts));
         Ok(outputs)
     }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use minijinja::{AutoEscape, Environment, UndefinedBehavior};
 use std::collections::HashMap;
 
 use crate::engine::context::ExecutionContext;
@@ -14,6 +15,19 @@ impl NodeExecutor for TextTemplateExecutor {
         "textTemplate"
     }
 
+    // A template wired to the input is only known at run time, so only the
+    // configured one is checked up front
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        let template = config
+            .get("template")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        let env = environment(config);
+        env.template_from_str(template)
+            .map(|_| ())
+            .map_err(|e| AppError::Validation(describe_error(&e, template)))
+    }
+
     async fn execute(
         &self,
         inputs: HashMap<String, NodeValue>,
@@ -26,28 +40,28 @@ impl NodeExecutor for TextTemplateExecutor {
             .or_else(|| config.get("template").and_then(|v| v.as_str()).map(String::from))
             .unwrap_or_default();
 
-        let mut result = template.clone();
-
-        // Interpolate from variables input (object)
+        // Every input is available by its port name; the fields of the
+        // `variables` object are also available directly, so `{{ name }}`
+        // and `{{ variables.name }}` both work
+        let mut context = serde_json::Map::new();
+        for (key, value) in &inputs {
+            if key != "template" {
+                context.insert(key.clone(), value.to_json_value());
+            }
+        }
         if let Some(NodeValue::Object(vars)) = inputs.get("variables") {
             for (key, value) in vars {
-                let placeholder = format!("{{{{{}}}}}", key);
-                let replacement = match value {
-                    serde_json::Value::String(s) => s.clone(),
-                    other => other.to_string(),
-                };
-                result = result.replace(&placeholder, &replacement);
+                context.insert(key.clone(), value.to_json_value());
             }
         }
 
-        // Also interpolate from any other inputs
-        for (key, value) in &inputs {
-            if key == "template" || key == "variables" {
-                continue;
-            }
-            let placeholder = format!("{{{{{}}}}}", key);
-            result = result.replace(&placeholder, &value.coerce_to_string());
-        }
+        let env = environment(&config);
+        let result = env
+            .render_str(&template, serde_json::Value::Object(context))
+            .map_err(|e| AppError::NodeExecution {
+                node_id: String::new(),
+                message: describe_error(&e, &template),
+            })?;
 
         let mut outputs = HashMap::new();
         outputs.insert("result".to_string(), NodeValue::String(result));
@@ -55,9 +69,58 @@ impl NodeExecutor for TextTemplateExecutor {
     }
 }
 
+// Templates use Jinja syntax: `{{ user.name }}`, `{% for item in items %}`,
+// `{% if ... %}` and filters such as `{{ name | upper }}`. Whitespace
+// around tags is kept unless trimmed with `{%-` and `-%}`, as in Jinja.
+fn environment(config: &serde_json::Value) -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_keep_trailing_newline(true);
+
+    // Missing variables render as empty text unless strict mode is on
+    let strict = config
+        .get("strict")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    env.set_undefined_behavior(if strict {
+        UndefinedBehavior::Strict
+    } else {
+        UndefinedBehavior::Chainable
+    });
+
+    let escape = match config.get("escape").and_then(|v| v.as_str()) {
+        Some("html") => AutoEscape::Html,
+        Some("json") => AutoEscape::Json,
+        _ => AutoEscape::None,
+    };
+    env.set_auto_escape_callback(move |_| escape);
+    env
+}
+
+// Points at the line and column of the failing tag where the engine knows it
+fn describe_error(e: &minijinja::Error, source: &str) -> String {
+    let what = match e.detail() {
+        Some(detail) => format!("{}: {}", e.kind(), detail),
+        None => e.kind().to_string(),
+    };
+    match (e.range(), e.line()) {
+        (Some(range), _) => {
+            let before = &source[..range.start.min(source.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+            format!(
+                "Template error at line {}, column {}: {}",
+                line, column, what
+            )
+        }
+        (None, Some(line)) => format!("Template error at line {}: {}", line, what),
+        (None, None) => format!("Template error: {}", what),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use indexmap::IndexMap;
 
     #[tokio::test]
     async fn test_template_interpolation() {
@@ -65,8 +128,8 @@ mod tests {
         let config = serde_json::json!({ "template": "Hello, {{name}}!" });
         let mut inputs = HashMap::new();
 
-        let mut vars = HashMap::new();
-        vars.insert("name".to_string(), serde_json::json!("World"));
+        let mut vars = IndexMap::new();
+        vars.insert("name".to_string(), NodeValue::String("World".to_string()));
         inputs.insert("variables".to_string(), NodeValue::Object(vars));
 
         let ctx = ExecutionContext::new();
@@ -86,8 +149,8 @@ mod tests {
             "template".to_string(),
             NodeValue::String("Value: {{x}}".to_string()),
         );
-        let mut vars = HashMap::new();
-        vars.insert("x".to_string(), serde_json::json!(42));
+        let mut vars = IndexMap::new();
+        vars.insert("x".to_string(), NodeValue::Integer(42));
         inputs.insert("variables".to_string(), NodeValue::Object(vars));
 
         let ctx = ExecutionContext::new();
@@ -97,4 +160,42 @@ mod tests {
             "Value: 42"
         );
     }
+
+    #[tokio::test]
+    async fn test_template_loops_conditionals_and_strict_mode() {
+        let template = "{% for user in variables.users -%}\n\
+                        - {{ user.name | upper }}{% if user.admin %} (admin){% endif %}\n\
+                        {% endfor %}\
+                        {{ missing.field }}<{{ variables.users | length }}>";
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "variables".to_string(),
+            NodeValue::from_json(serde_json::json!({
+                "users": [{ "name": "ada", "admin": true }, { "name": "<alan>" }]
+            })),
+        );
+        let ctx = ExecutionContext::new();
+        let config = serde_json::json!({ "template": template, "escape": "html" });
+        let result = TextTemplateExecutor
+            .execute(inputs.clone(), config, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            result["result"].as_string().unwrap(),
+            "- ADA (admin)\n- &lt;ALAN&gt;\n<2>"
+        );
+
+        let config = serde_json::json!({ "template": template, "strict": true });
+        let err = TextTemplateExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap_err();
+        // `user.admin` is missing on the second user
+        assert!(err.to_string().contains("line 2, column 32"));
+
+        let err = TextTemplateExecutor
+            .validate_config(&serde_json::json!({ "template": "Hi\n{{ name " }))
+            .unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
 }
@@ -0,0 +1,310 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use super::fields::config_int;
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct TextTransformExecutor;
+
+#[async_trait]
+impl NodeExecutor for TextTransformExecutor {
+    fn node_type(&self) -> &'static str {
+        "textTransform"
+    }
+
+    fn validate_config(&self, config: &serde_json::Value) -> Result<(), AppError> {
+        Operation::from_config(config).map(|_| ())
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let operation = Operation::from_config(&config)?;
+
+        // Arrays are transformed element-wise; anything else is used as text
+        let output = match inputs.get("input") {
+            Some(NodeValue::Array(items)) => NodeValue::Array(
+                items
+                    .iter()
+                    .map(|item| operation.apply(&item.coerce_to_string()))
+                    .collect(),
+            ),
+            Some(value) => operation.apply(&value.coerce_to_string()),
+            None => operation.apply(""),
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+enum Operation {
+    Upper,
+    Lower,
+    Title,
+    Trim,
+    TrimStart,
+    TrimEnd,
+    PadStart(usize, char),
+    PadEnd(usize, char),
+    // Character indices, end exclusive; negative values count from the end
+    Substring(i64, Option<i64>),
+    Replace {
+        find: String,
+        with: String,
+        all: bool,
+    },
+    Length,
+    Slugify,
+    NormalizeWhitespace,
+    Normalize(String),
+}
+
+impl Operation {
+    fn from_config(config: &serde_json::Value) -> Result<Self, AppError> {
+        let text = |key: &str| config.get(key).and_then(|v| v.as_str()).unwrap_or("");
+        let pad = || -> Result<(usize, char), AppError> {
+            let width = config_int(config, "width")?.unwrap_or(0).max(0) as usize;
+            let mut fill = text("padChar").chars();
+            match (fill.next(), fill.next()) {
+                (None, _) => Ok((width, ' ')),
+                (Some(c), None) => Ok((width, c)),
+                _ => Err(AppError::Validation(
+                    "Pad character must be a single character".to_string(),
+                )),
+            }
+        };
+
+        Ok(match text("operation") {
+            "upper" => Operation::Upper,
+            "lower" => Operation::Lower,
+            "title" => Operation::Title,
+            "trim" => Operation::Trim,
+            "trimStart" => Operation::TrimStart,
+            "trimEnd" => Operation::TrimEnd,
+            "padStart" => {
+                let (width, fill) = pad()?;
+                Operation::PadStart(width, fill)
+            }
+            "padEnd" => {
+                let (width, fill) = pad()?;
+                Operation::PadEnd(width, fill)
+            }
+            "substring" => Operation::Substring(
+                config_int(config, "start")?.unwrap_or(0),
+                config_int(config, "end")?,
+            ),
+            "replace" => {
+                if text("find").is_empty() {
+                    return Err(AppError::Validation(
+                        "Text Transform 'replace' needs the text to find".to_string(),
+                    ));
+                }
+                Operation::Replace {
+                    find: text("find").to_string(),
+                    with: text("replacement").to_string(),
+                    all: config
+                        .get("replaceAll")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(true),
+                }
+            }
+            "length" => Operation::Length,
+            "slugify" => Operation::Slugify,
+            "normalizeWhitespace" => Operation::NormalizeWhitespace,
+            "normalize" => match text("form") {
+                "" => Operation::Normalize("NFC".to_string()),
+                form @ ("NFC" | "NFD" | "NFKC" | "NFKD") => Operation::Normalize(form.to_string()),
+                other => {
+                    return Err(AppError::Validation(format!(
+                        "Unknown Unicode normalization form '{}' (expected NFC, NFD, NFKC or NFKD)",
+                        other
+                    )))
+                }
+            },
+            other => {
+                return Err(AppError::Validation(format!(
+                    "Unknown Text Transform operation '{}'",
+                    other
+                )))
+            }
+        })
+    }
+
+    fn apply(&self, s: &str) -> NodeValue {
+        NodeValue::String(match self {
+            Operation::Upper => s.to_uppercase(),
+            Operation::Lower => s.to_lowercase(),
+            Operation::Title => title_case(s),
+            Operation::Trim => s.trim().to_string(),
+            Operation::TrimStart => s.trim_start().to_string(),
+            Operation::TrimEnd => s.trim_end().to_string(),
+            Operation::PadStart(width, fill) => {
+                let missing = width.saturating_sub(s.chars().count());
+                std::iter::repeat_n(*fill, missing)
+                    .chain(s.chars())
+                    .collect()
+            }
+            Operation::PadEnd(width, fill) => {
+                let missing = width.saturating_sub(s.chars().count());
+                s.chars()
+                    .chain(std::iter::repeat_n(*fill, missing))
+                    .collect()
+            }
+            Operation::Substring(start, end) => {
+                let len = s.chars().count() as i64;
+                let resolve = |index: i64| {
+                    let index = if index < 0 { len + index } else { index };
+                    index.clamp(0, len) as usize
+                };
+                let start = resolve(*start);
+                let end = resolve(end.unwrap_or(len)).max(start);
+                s.chars().skip(start).take(end - start).collect()
+            }
+            Operation::Replace { find, with, all } => {
+                if *all {
+                    s.replace(find.as_str(), with)
+                } else {
+                    s.replacen(find.as_str(), with, 1)
+                }
+            }
+            Operation::Length => return NodeValue::Integer(s.chars().count() as i64),
+            Operation::Slugify => slugify(s),
+            Operation::NormalizeWhitespace => s.split_whitespace().collect::<Vec<_>>().join(" "),
+            Operation::Normalize(form) => match form.as_str() {
+                "NFD" => s.nfd().collect(),
+                "NFKC" => s.nfkc().collect(),
+                "NFKD" => s.nfkd().collect(),
+                _ => s.nfc().collect(),
+            },
+        })
+    }
+}
+
+// Upper-cases the first letter of every word and lower-cases the rest
+fn title_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut word_start = true;
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            if word_start {
+                result.extend(c.to_uppercase());
+            } else {
+                result.extend(c.to_lowercase());
+            }
+            word_start = false;
+        } else {
+            result.push(c);
+            word_start = c.is_whitespace() || c == '-' || c == '_';
+        }
+    }
+    result
+}
+
+// Accents are stripped (`Crème Brûlée` becomes `creme-brulee`) and every
+// run of other characters becomes a single dash
+fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    for c in s.nfkd().filter(|c| !is_combining_mark(*c)) {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn transform(input: serde_json::Value, config: serde_json::Value) -> serde_json::Value {
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::from_json(input));
+        let ctx = ExecutionContext::new();
+        let result = TextTransformExecutor
+            .execute(inputs, config, &ctx)
+            .await
+            .unwrap();
+        result["output"].to_json_value()
+    }
+
+    #[tokio::test]
+    async fn test_text_operations() {
+        let cases = [
+            (
+                "title",
+                serde_json::json!({}),
+                "hello wORLD-wide",
+                "Hello World-Wide",
+            ),
+            (
+                "slugify",
+                serde_json::json!({}),
+                "  Crème Brûlée: 2 ways! ",
+                "creme-brulee-2-ways",
+            ),
+            (
+                "padStart",
+                serde_json::json!({ "width": 5, "padChar": "0" }),
+                "42",
+                "00042",
+            ),
+            (
+                "substring",
+                serde_json::json!({ "start": -4, "end": -1 }),
+                "naïveté",
+                "vet",
+            ),
+            (
+                "replace",
+                serde_json::json!({ "find": "a.", "replacement": "b", "replaceAll": false }),
+                "a.a.",
+                "ba.",
+            ),
+            (
+                "normalizeWhitespace",
+                serde_json::json!({}),
+                " a \n\t b  c ",
+                "a b c",
+            ),
+            (
+                "normalize",
+                serde_json::json!({ "form": "NFKC" }),
+                "ﬁ①",
+                "fi1",
+            ),
+        ];
+        for (operation, mut config, input, expected) in cases {
+            config["operation"] = serde_json::json!(operation);
+            assert_eq!(
+                transform(serde_json::json!(input), config).await,
+                serde_json::json!(expected),
+                "{}",
+                operation
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_text_operations_on_arrays() {
+        let config = serde_json::json!({ "operation": "length" });
+        assert_eq!(
+            transform(serde_json::json!(["héllo", "", 123]), config).await,
+            serde_json::json!([5, 0, 3])
+        );
+        assert!(TextTransformExecutor
+            .validate_config(&serde_json::json!({ "operation": "normalize", "form": "NFX" }))
+            .is_err());
+    }
+}
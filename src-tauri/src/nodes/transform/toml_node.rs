@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct TomlParseExecutor;
+
+#[async_trait]
+impl NodeExecutor for TomlParseExecutor {
+    fn node_type(&self) -> &'static str {
+        "tomlParse"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        _config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let input = inputs
+            .get("input")
+            .and_then(|v| v.as_string())
+            .ok_or_else(|| toml_error("No input provided to TOML Parse".to_string()))?;
+
+        let table: toml::Table =
+            toml::from_str(&input).map_err(|e| toml_error(format!("Invalid TOML: {}", e)))?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), from_toml(toml::Value::Table(table)));
+        Ok(outputs)
+    }
+}
+
+pub struct TomlSerializeExecutor;
+
+#[async_trait]
+impl NodeExecutor for TomlSerializeExecutor {
+    fn node_type(&self) -> &'static str {
+        "tomlSerialize"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        _config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let value = inputs
+            .get("input")
+            .map(|v| v.to_json_value())
+            .unwrap_or(serde_json::Value::Null);
+
+        let table = match to_toml(value).map_err(toml_error)? {
+            Some(toml::Value::Table(table)) => table,
+            _ => {
+                return Err(toml_error(
+                    "TOML Serialize expects an object at the top level".to_string(),
+                ))
+            }
+        };
+        let serialized = toml::to_string(&table)
+            .map_err(|e| toml_error(format!("Failed to serialize TOML: {}", e)))?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::String(serialized));
+        Ok(outputs)
+    }
+}
+
+// Datetimes have no NodeValue counterpart and are kept as their TOML text
+fn from_toml(value: toml::Value) -> NodeValue {
+    match value {
+        toml::Value::String(s) => NodeValue::String(s),
+        toml::Value::Integer(i) => NodeValue::Integer(i),
+        toml::Value::Float(f) => NodeValue::Number(f),
+        toml::Value::Boolean(b) => NodeValue::Boolean(b),
+        toml::Value::Datetime(dt) => NodeValue::String(dt.to_string()),
+        toml::Value::Array(items) => NodeValue::Array(items.into_iter().map(from_toml).collect()),
+        toml::Value::Table(table) => {
+            let mut obj = IndexMap::new();
+            for (key, value) in table {
+                obj.insert(key, from_toml(value));
+            }
+            NodeValue::Object(obj)
+        }
+    }
+}
+
+// TOML has no null: null object values are left out, and null inside an
+// array is an error
+fn to_toml(value: serde_json::Value) -> Result<Option<toml::Value>, String> {
+    Ok(Some(match value {
+        serde_json::Value::Null => return Ok(None),
+        serde_json::Value::Bool(b) => toml::Value::Boolean(b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => toml::Value::Integer(i),
+            None => toml::Value::Float(n.as_f64().unwrap_or(f64::NAN)),
+        },
+        serde_json::Value::String(s) => toml::Value::String(s),
+        serde_json::Value::Array(items) => toml::Value::Array(
+            items
+                .into_iter()
+                .map(|item| {
+                    to_toml(item)?.ok_or_else(|| "TOML arrays cannot contain null".to_string())
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_json::Value::Object(obj) => {
+            let mut table = toml::Table::new();
+            for (key, value) in obj {
+                if let Some(value) = to_toml(value)? {
+                    table.insert(key, value);
+                }
+            }
+            toml::Value::Table(table)
+        }
+    }))
+}
+
+fn toml_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_toml_round_trip() {
+        let toml = "title = \"flow\"\nretries = 3\n\n[server]\nhost = \"localhost\"\nstarted = 2024-01-02T03:04:05Z\n";
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::String(toml.to_string()));
+        let ctx = ExecutionContext::new();
+        let mut parsed = TomlParseExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+
+        let output = parsed.remove("output").unwrap();
+        assert!(matches!(output.get("retries"), Some(NodeValue::Integer(3))));
+        let server = output.get("server").unwrap();
+        assert_eq!(
+            server.get("started").unwrap().as_string().unwrap(),
+            "2024-01-02T03:04:05Z"
+        );
+
+        let NodeValue::Object(mut obj) = output else {
+            panic!("Expected object output");
+        };
+        obj.insert("skipped".to_string(), NodeValue::Null);
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::Object(obj));
+        let serialized = TomlSerializeExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            serialized["output"].as_string().unwrap(),
+            "title = \"flow\"\nretries = 3\n\n[server]\nhost = \"localhost\"\nstarted = \"2024-01-02T03:04:05Z\"\n"
+        );
+    }
+}
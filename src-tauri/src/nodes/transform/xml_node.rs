@@ -0,0 +1,417 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+// Attributes become keys with this prefix and the text of an element that
+// also has attributes or children goes under the text key, e.g.
+// `<item id="1">Hi</item>` <-> `{ "item": { "@id": "1", "#text": "Hi" } }`.
+// Repeated child elements collect into an array.
+const ATTRIBUTE_PREFIX: &str = "@";
+const TEXT_KEY: &str = "#text";
+
+pub struct XmlParseExecutor;
+
+#[async_trait]
+impl NodeExecutor for XmlParseExecutor {
+    fn node_type(&self) -> &'static str {
+        "xmlParse"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let input = inputs
+            .get("input")
+            .and_then(|v| v.as_string())
+            .ok_or_else(|| xml_error("No input provided to XML Parse".to_string()))?;
+        let conventions = Conventions::from_config(&config);
+        let infer_types = config
+            .get("inferTypes")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let output = parse_xml(&input, &conventions, infer_types)
+            .map_err(|e| xml_error(format!("Invalid XML: {}", e)))?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+pub struct XmlSerializeExecutor;
+
+#[async_trait]
+impl NodeExecutor for XmlSerializeExecutor {
+    fn node_type(&self) -> &'static str {
+        "xmlSerialize"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let value = inputs.get("input").cloned().unwrap_or_default();
+        let conventions = Conventions::from_config(&config);
+        let root_name = config
+            .get("rootName")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        let pretty = config
+            .get("pretty")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let declaration = config
+            .get("declaration")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        // Without a root name the input must be a single-key object naming
+        // the root element, which is what XML Parse produces
+        let (name, root) = if !root_name.is_empty() {
+            (root_name.to_string(), &value)
+        } else {
+            match &value {
+                NodeValue::Object(obj) if obj.len() == 1 => {
+                    let (name, root) = obj.first().unwrap();
+                    (name.clone(), root)
+                }
+                _ => return Err(xml_error(
+                    "XML Serialize needs an object with a single root key, or a Root Element name"
+                        .to_string(),
+                )),
+            }
+        };
+
+        let mut writer = if pretty {
+            Writer::new_with_indent(Vec::new(), b' ', 2)
+        } else {
+            Writer::new(Vec::new())
+        };
+        if declaration {
+            write(
+                &mut writer,
+                Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)),
+            )?;
+        }
+        write_element(&mut writer, &name, root, &conventions)?;
+
+        let mut xml = String::from_utf8_lossy(&writer.into_inner()).into_owned();
+        if pretty {
+            xml.push('\n');
+        }
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::String(xml));
+        Ok(outputs)
+    }
+}
+
+struct Conventions {
+    attribute_prefix: String,
+    text_key: String,
+}
+
+impl Conventions {
+    fn from_config(config: &serde_json::Value) -> Self {
+        let setting = |key: &str, default: &str| {
+            config
+                .get(key)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .unwrap_or(default)
+                .to_string()
+        };
+        Self {
+            attribute_prefix: setting("attributePrefix", ATTRIBUTE_PREFIX),
+            text_key: setting("textKey", TEXT_KEY),
+        }
+    }
+}
+
+struct Element {
+    name: String,
+    fields: IndexMap<String, NodeValue>,
+    text: String,
+}
+
+impl Element {
+    fn start(
+        e: &BytesStart,
+        conventions: &Conventions,
+        infer_types: bool,
+    ) -> Result<Self, quick_xml::Error> {
+        let mut fields = IndexMap::new();
+        for attr in e.attributes() {
+            let attr = attr.map_err(quick_xml::Error::from)?;
+            let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+            let value = attr.unescape_value()?;
+            fields.insert(
+                format!("{}{}", conventions.attribute_prefix, key),
+                scalar(&value, infer_types),
+            );
+        }
+        Ok(Self {
+            name: String::from_utf8_lossy(e.name().as_ref()).into_owned(),
+            fields,
+            text: String::new(),
+        })
+    }
+
+    // An element with nothing but text becomes that text; an empty one
+    // becomes null
+    fn finish(self, conventions: &Conventions, infer_types: bool) -> (String, NodeValue) {
+        let text = self.text.trim();
+        let value = if self.fields.is_empty() {
+            if text.is_empty() {
+                NodeValue::Null
+            } else {
+                scalar(text, infer_types)
+            }
+        } else {
+            let mut fields = self.fields;
+            if !text.is_empty() {
+                fields.insert(conventions.text_key.clone(), scalar(text, infer_types));
+            }
+            NodeValue::Object(fields)
+        };
+        (self.name, value)
+    }
+
+    // Element values are never arrays themselves, so an array under a child
+    // name always means the element was repeated
+    fn add_child(&mut self, name: String, value: NodeValue) {
+        match self.fields.get_mut(&name) {
+            Some(NodeValue::Array(items)) => items.push(value),
+            Some(existing) => {
+                let first = std::mem::take(existing);
+                *existing = NodeValue::Array(vec![first, value]);
+            }
+            None => {
+                self.fields.insert(name, value);
+            }
+        }
+    }
+}
+
+fn parse_xml(
+    input: &str,
+    conventions: &Conventions,
+    infer_types: bool,
+) -> Result<NodeValue, String> {
+    let mut reader = Reader::from_str(input);
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root: Option<(String, NodeValue)> = None;
+
+    let mut close = |element: Element, stack: &mut Vec<Element>| {
+        let (name, value) = element.finish(conventions, infer_types);
+        match stack.last_mut() {
+            Some(parent) => parent.add_child(name, value),
+            None if root.is_some() => return Err("more than one root element".to_string()),
+            None => root = Some((name, value)),
+        }
+        Ok(())
+    };
+
+    loop {
+        match reader.read_event().map_err(|e| e.to_string())? {
+            Event::Start(e) => {
+                stack.push(Element::start(&e, conventions, infer_types).map_err(|e| e.to_string())?)
+            }
+            Event::Empty(e) => {
+                let element =
+                    Element::start(&e, conventions, infer_types).map_err(|e| e.to_string())?;
+                close(element, &mut stack)?;
+            }
+            Event::End(_) => {
+                if let Some(element) = stack.pop() {
+                    close(element, &mut stack)?;
+                }
+            }
+            Event::Text(e) => {
+                if let Some(element) = stack.last_mut() {
+                    element
+                        .text
+                        .push_str(&e.decode().map_err(|e| e.to_string())?);
+                }
+            }
+            Event::CData(e) => {
+                if let Some(element) = stack.last_mut() {
+                    element
+                        .text
+                        .push_str(&e.decode().map_err(|e| e.to_string())?);
+                }
+            }
+            Event::GeneralRef(e) => {
+                let resolved = match e.resolve_char_ref().map_err(|e| e.to_string())? {
+                    Some(ch) => ch.to_string(),
+                    None => {
+                        let name = e.decode().map_err(|e| e.to_string())?;
+                        resolve_predefined_entity(&name)
+                            .ok_or_else(|| format!("unknown entity '&{};'", name))?
+                            .to_string()
+                    }
+                };
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(&resolved);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if let Some(element) = stack.last() {
+        return Err(format!("element <{}> is not closed", element.name));
+    }
+    let (name, value) = root.ok_or_else(|| "no root element".to_string())?;
+    let mut obj = IndexMap::new();
+    obj.insert(name, value);
+    Ok(NodeValue::Object(obj))
+}
+
+fn write_element(
+    writer: &mut Writer<Vec<u8>>,
+    name: &str,
+    value: &NodeValue,
+    conventions: &Conventions,
+) -> Result<(), AppError> {
+    check_name(name)?;
+    match value {
+        NodeValue::Array(items) => {
+            for item in items {
+                write_element(writer, name, item, conventions)?;
+            }
+            Ok(())
+        }
+        NodeValue::Object(obj) => {
+            let mut start = BytesStart::new(name);
+            let mut text = None;
+            let mut children = Vec::new();
+            for (key, value) in obj {
+                if *key == conventions.text_key {
+                    text = Some(value.coerce_to_string());
+                } else if let Some(attribute) = key.strip_prefix(&conventions.attribute_prefix) {
+                    check_name(attribute)?;
+                    start.push_attribute((attribute, value.coerce_to_string().as_str()));
+                } else {
+                    children.push((key, value));
+                }
+            }
+
+            if text.is_none() && children.is_empty() {
+                return write(writer, Event::Empty(start));
+            }
+            write(writer, Event::Start(start))?;
+            if let Some(text) = text {
+                write(writer, Event::Text(BytesText::new(&text)))?;
+            }
+            for (key, value) in children {
+                write_element(writer, key, value, conventions)?;
+            }
+            write(writer, Event::End(BytesEnd::new(name)))
+        }
+        NodeValue::Null => write(writer, Event::Empty(BytesStart::new(name))),
+        other => {
+            write(writer, Event::Start(BytesStart::new(name)))?;
+            write(
+                writer,
+                Event::Text(BytesText::new(&other.coerce_to_string())),
+            )?;
+            write(writer, Event::End(BytesEnd::new(name)))
+        }
+    }
+}
+
+fn write(writer: &mut Writer<Vec<u8>>, event: Event) -> Result<(), AppError> {
+    writer
+        .write_event(event)
+        .map_err(|e| xml_error(format!("Failed to write XML: {}", e)))
+}
+
+fn check_name(name: &str) -> Result<(), AppError> {
+    let mut chars = name.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_' || c == ':')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(xml_error(format!("'{}' is not a valid XML name", name)))
+    }
+}
+
+fn scalar(text: &str, infer_types: bool) -> NodeValue {
+    if infer_types {
+        NodeValue::infer_from_str(text)
+    } else {
+        NodeValue::String(text.to_string())
+    }
+}
+
+fn xml_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_xml_attributes_text_and_repeated_elements() {
+        let xml = r#"<?xml version="1.0"?>
+<feed lang="en">
+  <title>News &amp; Notes</title>
+  <entry id="1"><name>First</name></entry>
+  <entry id="2">Second &#x2713;<draft/></entry>
+</feed>"#;
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::String(xml.to_string()));
+        let ctx = ExecutionContext::new();
+        let mut parsed = XmlParseExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+
+        let output = parsed.remove("output").unwrap();
+        assert_eq!(
+            output.to_json_value(),
+            serde_json::json!({
+                "feed": {
+                    "@lang": "en",
+                    "title": "News & Notes",
+                    "entry": [
+                        { "@id": "1", "name": "First" },
+                        { "@id": "2", "draft": null, "#text": "Second ✓" }
+                    ]
+                }
+            })
+        );
+
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), output);
+        let serialized = XmlSerializeExecutor
+            .execute(inputs, serde_json::json!({ "declaration": false }), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            serialized["output"].as_string().unwrap(),
+            "<feed lang=\"en\">\n  <title>News &amp; Notes</title>\n  <entry id=\"1\">\n    <name>First</name>\n  </entry>\n  <entry id=\"2\">Second ✓<draft/>\n  </entry>\n</feed>\n"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_yaml_ng::Value as YamlValue;
+use std::collections::HashMap;
+
+use crate::engine::context::ExecutionContext;
+use crate::error::AppError;
+use crate::nodes::NodeExecutor;
+use crate::types::NodeValue;
+
+pub struct YamlParseExecutor;
+
+#[async_trait]
+impl NodeExecutor for YamlParseExecutor {
+    fn node_type(&self) -> &'static str {
+        "yamlParse"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let input = inputs
+            .get("input")
+            .and_then(|v| v.as_string())
+            .ok_or_else(|| yaml_error("No input provided to YAML Parse".to_string()))?;
+        let multi_document = config
+            .get("multiDocument")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let output = if multi_document {
+            let mut documents = Vec::new();
+            for document in serde_yaml_ng::Deserializer::from_str(&input) {
+                let value = YamlValue::deserialize(document)
+                    .map_err(|e| yaml_error(format!("Invalid YAML: {}", e)))?;
+                documents.push(from_yaml(value));
+            }
+            NodeValue::Array(documents)
+        } else {
+            let value: YamlValue = serde_yaml_ng::from_str(&input)
+                .map_err(|e| yaml_error(format!("Invalid YAML: {}", e)))?;
+            from_yaml(value)
+        };
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), output);
+        Ok(outputs)
+    }
+}
+
+pub struct YamlSerializeExecutor;
+
+#[async_trait]
+impl NodeExecutor for YamlSerializeExecutor {
+    fn node_type(&self) -> &'static str {
+        "yamlSerialize"
+    }
+
+    async fn execute(
+        &self,
+        inputs: HashMap<String, NodeValue>,
+        _config: serde_json::Value,
+        _ctx: &ExecutionContext,
+    ) -> Result<HashMap<String, NodeValue>, AppError> {
+        let value = inputs
+            .get("input")
+            .map(|v| v.to_json_value())
+            .unwrap_or(serde_json::Value::Null);
+        let serialized = serde_yaml_ng::to_string(&value)
+            .map_err(|e| yaml_error(format!("Failed to serialize YAML: {}", e)))?;
+
+        let mut outputs = HashMap::new();
+        outputs.insert("output".to_string(), NodeValue::String(serialized));
+        Ok(outputs)
+    }
+}
+
+// Tags are dropped and non-string keys use their YAML text, so any document
+// maps onto plain values
+fn from_yaml(value: YamlValue) -> NodeValue {
+    match value {
+        YamlValue::Null => NodeValue::Null,
+        YamlValue::Bool(b) => NodeValue::Boolean(b),
+        YamlValue::Number(n) => match n.as_i64() {
+            Some(i) => NodeValue::Integer(i),
+            None => NodeValue::Number(n.as_f64().unwrap_or(f64::NAN)),
+        },
+        YamlValue::String(s) => NodeValue::String(s),
+        YamlValue::Sequence(items) => NodeValue::Array(items.into_iter().map(from_yaml).collect()),
+        YamlValue::Mapping(mapping) => {
+            let mut obj = IndexMap::new();
+            for (key, value) in mapping {
+                obj.insert(yaml_key(key), from_yaml(value));
+            }
+            NodeValue::Object(obj)
+        }
+        YamlValue::Tagged(tagged) => from_yaml(tagged.value),
+    }
+}
+
+fn yaml_key(key: YamlValue) -> String {
+    match key {
+        YamlValue::String(s) => s,
+        other => serde_yaml_ng::to_string(&other)
+            .map(|s| s.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn yaml_error(message: String) -> AppError {
+    AppError::NodeExecution {
+        node_id: String::new(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_yaml_round_trip_keeps_order_and_types() {
+        let yaml = "name: signalflow\nport: 8080\nratio: 0.5\ntags: [a, b]\n1: one\nempty:\n";
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), NodeValue::String(yaml.to_string()));
+        let ctx = ExecutionContext::new();
+        let mut parsed = YamlParseExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+
+        let output = parsed.remove("output").unwrap();
+        let NodeValue::Object(obj) = &output else {
+            panic!("Expected object output");
+        };
+        let keys: Vec<&str> = obj.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["name", "port", "ratio", "tags", "1", "empty"]);
+        assert!(matches!(obj["port"], NodeValue::Integer(8080)));
+        assert!(matches!(obj["empty"], NodeValue::Null));
+
+        let mut inputs = HashMap::new();
+        inputs.insert("input".to_string(), output);
+        let serialized = YamlSerializeExecutor
+            .execute(inputs, serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            serialized["output"].as_string().unwrap(),
+            "name: signalflow\nport: 8080\nratio: 0.5\ntags:\n- a\n- b\n'1': one\nempty: null\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_yaml_multi_document() {
+        let mut inputs = HashMap::new();
+        inputs.insert(
+            "input".to_string(),
+            NodeValue::String("a: 1\n---\na: 2\n".to_string()),
+        );
+        let ctx = ExecutionContext::new();
+        let result = YamlParseExecutor
+            .execute(inputs, serde_json::json!({ "multiDocument": true }), &ctx)
+            .await
+            .unwrap();
+        let NodeValue::Array(documents) = &result["output"] else {
+            panic!("Expected array output");
+        };
+        assert_eq!(documents.len(), 2);
+        assert!(matches!(documents[1].get("a"), Some(NodeValue::Integer(2))));
+    }
+}
@@ -1,6 +1,12 @@
-use serde::{Deserialize, Serialize};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use indexmap::IndexMap;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 
+use crate::engine::stream::ValueStream;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FlowDocument {
     pub id: Option<String>,
@@ -43,22 +49,75 @@ pub struct Viewport {
     pub zoom: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
+// Key marking special variants in their JSON form, e.g. `{"$type": "file", "path": "..."}`
+// or `{"$type": "bytes", "base64": "..."}`
+pub const TYPE_KEY: &str = "$type";
+
+#[derive(Debug, Clone, Default)]
 pub enum NodeValue {
+    #[default]
     Null,
     Boolean(bool),
+    Integer(i64),
     Number(f64),
     String(String),
     Array(Vec<NodeValue>),
-    Object(HashMap<String, serde_json::Value>),
+    Object(IndexMap<String, NodeValue>),
     File { path: String },
+    Bytes(Vec<u8>),
+    // Lazily read data, see `engine::stream`. Never serialized with its contents.
+    Stream(ValueStream),
+}
+
+impl Serialize for NodeValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            NodeValue::Null => serializer.serialize_unit(),
+            NodeValue::Boolean(b) => serializer.serialize_bool(*b),
+            NodeValue::Integer(i) => serializer.serialize_i64(*i),
+            NodeValue::Number(n) => serializer.serialize_f64(*n),
+            NodeValue::String(s) => serializer.serialize_str(s),
+            NodeValue::Array(arr) => arr.serialize(serializer),
+            NodeValue::Object(obj) => {
+                let mut map = serializer.serialize_map(Some(obj.len()))?;
+                for (k, v) in obj {
+                    map.serialize_entry(k, v)?;
+                }
+                map.end()
+            }
+            NodeValue::File { path } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(TYPE_KEY, "file")?;
+                map.serialize_entry("path", path)?;
+                map.end()
+            }
+            NodeValue::Bytes(bytes) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(TYPE_KEY, "bytes")?;
+                map.serialize_entry("base64", &BASE64.encode(bytes))?;
+                map.end()
+            }
+            NodeValue::Stream(stream) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(TYPE_KEY, "stream")?;
+                map.serialize_entry("description", &stream.describe())?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(NodeValue::from_json)
+    }
 }
 
 impl NodeValue {
     pub fn as_string(&self) -> Option<String> {
         match self {
             NodeValue::String(s) => Some(s.clone()),
+            NodeValue::Integer(i) => Some(i.to_string()),
             NodeValue::Number(n) => Some(n.to_string()),
             NodeValue::Boolean(b) => Some(b.to_string()),
             NodeValue::Null => Some("null".to_string()),
@@ -68,6 +127,7 @@ impl NodeValue {
 
     pub fn as_number(&self) -> Option<f64> {
         match self {
+            NodeValue::Integer(i) => Some(*i as f64),
             NodeValue::Number(n) => Some(*n),
             NodeValue::String(s) => s.parse().ok(),
             NodeValue::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
@@ -79,6 +139,7 @@ impl NodeValue {
         match self {
             NodeValue::Boolean(b) => Some(*b),
             NodeValue::String(s) => Some(!s.is_empty()),
+            NodeValue::Integer(i) => Some(*i != 0),
             NodeValue::Number(n) => Some(*n != 0.0),
             NodeValue::Null => Some(false),
             _ => Some(true),
@@ -88,12 +149,15 @@ impl NodeValue {
     pub fn preview(&self, max_len: usize) -> String {
         let s = match self {
             NodeValue::String(s) => s.clone(),
-            NodeValue::Number(n) => n.to_string(),
+            NodeValue::Integer(i) => i.to_string(),
+            NodeValue::Number(n) => format_float(*n),
             NodeValue::Boolean(b) => b.to_string(),
             NodeValue::Null => "null".to_string(),
             NodeValue::Array(arr) => format!("Array[{}]", arr.len()),
             NodeValue::Object(obj) => format!("Object{{{} keys}}", obj.len()),
             NodeValue::File { path } => format!("File: {path}"),
+            NodeValue::Bytes(bytes) => format!("Bytes ({})", format_size(bytes.len())),
+            NodeValue::Stream(stream) => stream.describe(),
         };
         if s.len() > max_len {
             format!("{}...", &s[..max_len])
@@ -106,6 +170,7 @@ impl NodeValue {
         match self {
             NodeValue::Null => serde_json::Value::Null,
             NodeValue::Boolean(b) => serde_json::Value::Bool(*b),
+            NodeValue::Integer(i) => serde_json::json!(i),
             NodeValue::Number(n) => serde_json::json!(n),
             NodeValue::String(s) => serde_json::Value::String(s.clone()),
             NodeValue::Array(arr) => {
@@ -113,17 +178,41 @@ impl NodeValue {
             }
             NodeValue::Object(obj) => serde_json::Value::Object(
                 obj.iter()
-                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .map(|(k, v)| (k.clone(), v.to_json_value()))
                     .collect(),
             ),
-            NodeValue::File { path } => serde_json::json!({ "path": path }),
+            NodeValue::File { path } => serde_json::json!({ TYPE_KEY: "file", "path": path }),
+            NodeValue::Bytes(bytes) => {
+                serde_json::json!({ TYPE_KEY: "bytes", "base64": BASE64.encode(bytes) })
+            }
+            NodeValue::Stream(stream) => {
+                serde_json::json!({ TYPE_KEY: "stream", "description": stream.describe() })
+            }
         }
     }
 }
 
-impl Default for NodeValue {
-    fn default() -> Self {
-        NodeValue::Null
+// Floats always show a fractional part so they can be told apart from integers
+fn format_float(n: f64) -> String {
+    if n.is_finite() && n.fract() == 0.0 && n.abs() < 1e16 {
+        format!("{:.1}", n)
+    } else {
+        n.to_string()
+    }
+}
+
+fn format_size(len: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = len as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", len)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
     }
 }
 
@@ -145,6 +234,8 @@ pub enum ExecutionEvent {
 pub struct ExecutionResult {
     pub success: bool,
     pub total_duration_ms: u64,
+    // Largest total size of node outputs held in memory at once during the run
+    pub peak_retained_bytes: u64,
     pub node_results: HashMap<String, NodeResult>,
     pub error: Option<String>,
 }
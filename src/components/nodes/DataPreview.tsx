@@ -1,5 +1,6 @@
 import { memo } from "react";
 import { useExecutionStore } from "../../stores/executionStore";
+import { bytesLength, formatByteSize, streamDescription } from "../../lib/nodeValue";
 
 function formatPreview(data: unknown): string {
   if (data == null) return "";
@@ -20,6 +21,16 @@ function formatPreview(data: unknown): string {
     return `Array[${data.length}]: [${items.join(", ")}${suffix}]`;
   }
 
+  const byteCount = bytesLength(data);
+  if (byteCount !== null) {
+    return `Bytes (${formatByteSize(byteCount)})`;
+  }
+
+  const stream = streamDescription(data);
+  if (stream !== null) {
+    return stream;
+  }
+
   if (typeof data === "object") {
     const keys = Object.keys(data);
     // If it's an output map with a single key, show that value's preview
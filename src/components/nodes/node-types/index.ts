@@ -19,6 +19,7 @@ export const nodeTypes: NodeTypes = {
   textTemplate: TextTemplateNode,
   fileRead: FileReadNode,
   fileWrite: FileWriteNode,
+  fileOps: GenericNode,
   httpRequest: HttpRequestNode,
   regex: RegexNode,
   conditional: ConditionalNode,
@@ -27,8 +28,43 @@ export const nodeTypes: NodeTypes = {
   code: CodeNode,
   // Remaining simple nodes use GenericNode
   jsonParse: GenericNode,
+  jsonSerialize: GenericNode,
   filter: GenericNode,
   map: GenericNode,
   merge: GenericNode,
   split: GenericNode,
+  csvParse: GenericNode,
+  csvSerialize: GenericNode,
+  yamlParse: GenericNode,
+  yamlSerialize: GenericNode,
+  tomlParse: GenericNode,
+  tomlSerialize: GenericNode,
+  xmlParse: GenericNode,
+  xmlSerialize: GenericNode,
+  query: GenericNode,
+  sort: GenericNode,
+  groupBy: GenericNode,
+  aggregate: GenericNode,
+  dedupe: GenericNode,
+  join: GenericNode,
+  objectTransform: GenericNode,
+  flatten: GenericNode,
+  chunk: GenericNode,
+  zip: GenericNode,
+  slice: GenericNode,
+  reverse: GenericNode,
+  unique: GenericNode,
+  length: GenericNode,
+  reduce: GenericNode,
+  textTransform: GenericNode,
+  math: GenericNode,
+  dateTime: GenericNode,
+  encode: GenericNode,
+  hash: GenericNode,
+  hmac: GenericNode,
+  compress: GenericNode,
+  archiveExtract: GenericNode,
+  archiveCreate: GenericNode,
+  uuid: GenericNode,
+  directoryList: GenericNode,
 };
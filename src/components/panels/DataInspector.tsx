@@ -1,5 +1,6 @@
 import { useState, memo } from "react";
 import { ChevronRight, ChevronDown, Copy, Check } from "lucide-react";
+import { bytesLength, formatByteSize, streamDescription } from "../../lib/nodeValue";
 
 function TypeBadge({ type }: { type: string }) {
   const colors: Record<string, string> = {
@@ -9,6 +10,8 @@ function TypeBadge({ type }: { type: string }) {
     null: "text-text-secondary",
     array: "text-purple-400",
     object: "text-cyan-400",
+    bytes: "text-orange-400",
+    stream: "text-teal-400",
   };
   return (
     <span className={`text-[9px] ${colors[type] ?? "text-text-secondary"}`}>
@@ -47,6 +50,26 @@ function JsonValue({
     return <span className="text-amber-400">{String(value)}</span>;
   }
 
+  const byteCount = bytesLength(value);
+  if (byteCount !== null) {
+    return (
+      <span className="inline-flex items-center gap-1">
+        <TypeBadge type="bytes" />
+        <span className="text-orange-400">{formatByteSize(byteCount)}</span>
+      </span>
+    );
+  }
+
+  const stream = streamDescription(value);
+  if (stream !== null) {
+    return (
+      <span className="inline-flex items-center gap-1">
+        <TypeBadge type="stream" />
+        <span className="text-teal-400">{stream}</span>
+      </span>
+    );
+  }
+
   if (depth >= maxDepth) {
     if (Array.isArray(value)) {
       return <span className="text-text-secondary">Array[{value.length}]</span>;
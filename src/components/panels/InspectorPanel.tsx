@@ -97,6 +97,15 @@ function ConfigFieldRenderer({
           onChange={onChange}
         />
       );
+    case "directory-path":
+      return (
+        <FilePathField
+          label={field.label}
+          value={String(value ?? "")}
+          mode="directory"
+          onChange={onChange}
+        />
+      );
     case "model-select":
       return (
         <ModelSelectField
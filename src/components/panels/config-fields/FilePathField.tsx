@@ -4,15 +4,15 @@ import { FolderOpen } from "lucide-react";
 interface FilePathFieldProps {
   label: string;
   value: string;
-  mode: "open" | "save";
+  mode: "open" | "save" | "directory";
   onChange: (value: string) => void;
 }
 
 export function FilePathField({ label, value, mode, onChange }: FilePathFieldProps) {
   const handleBrowse = async () => {
     try {
-      if (mode === "open") {
-        const result = await open({ multiple: false, directory: false });
+      if (mode === "open" || mode === "directory") {
+        const result = await open({ multiple: false, directory: mode === "directory" });
         if (result) {
           onChange(result);
         }
@@ -37,7 +37,7 @@ export function FilePathField({ label, value, mode, onChange }: FilePathFieldPro
           type="text"
           className="flex-1 rounded border border-panel-border bg-canvas-bg px-2 py-1 text-xs text-text-primary focus:border-accent focus:outline-none"
           value={value}
-          placeholder="/path/to/file"
+          placeholder={mode === "directory" ? "/path/to/folder" : "/path/to/file"}
           onChange={(e) => onChange(e.target.value)}
         />
         <button
@@ -9,6 +9,7 @@ import {
   type FlowDocument,
 } from "../lib/tauri";
 import { validateFlow } from "../lib/flowValidator";
+import { formatByteSize } from "../lib/nodeValue";
 import { useToast } from "./useToast";
 
 export function useExecution() {
@@ -125,7 +126,7 @@ export function useExecution() {
         completeExecution(result.total_duration_ms);
         addLog({
           level: "info",
-          message: `Execution completed in ${result.total_duration_ms}ms`,
+          message: `Execution completed in ${result.total_duration_ms}ms (peak memory held by outputs: ${formatByteSize(result.peak_retained_bytes)})`,
         });
         toast({
           title: "Execution complete",
@@ -7,6 +7,7 @@ const nodes = [
   { id: "n2", type: "debug" },
   { id: "n3", type: "numberInput" },
   { id: "n4", type: "filter" },
+  { id: "n5", type: "merge" },
 ];
 
 describe("isValidConnection", () => {
@@ -61,4 +62,29 @@ describe("isValidConnection", () => {
     );
     expect(result.valid).toBe(true);
   });
+
+  it("rejects a second connection into a single-connection input", () => {
+    const existing: Edge[] = [
+      { id: "e1", source: "n1", target: "n2", sourceHandle: "value", targetHandle: "input" },
+    ];
+    const result = isValidConnection(
+      { source: "n3", target: "n2", sourceHandle: "value", targetHandle: "input" },
+      nodes,
+      existing
+    );
+    expect(result.valid).toBe(false);
+    expect(result.reason).toContain("only one connection");
+  });
+
+  it("accepts several connections into a multiple input", () => {
+    const existing: Edge[] = [
+      { id: "e1", source: "n1", target: "n5", sourceHandle: "value", targetHandle: "inputs" },
+    ];
+    const result = isValidConnection(
+      { source: "n3", target: "n5", sourceHandle: "value", targetHandle: "inputs" },
+      nodes,
+      existing
+    );
+    expect(result.valid).toBe(true);
+  });
 });
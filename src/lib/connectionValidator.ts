@@ -63,6 +63,17 @@ export function isValidConnection(
     return { valid: true };
   }
 
+  if (!targetPort.multiple) {
+    const occupied = edges.some(
+      (e) =>
+        e.target === connection.target &&
+        (e.targetHandle ?? null) === (connection.targetHandle ?? null)
+    );
+    if (occupied) {
+      return { valid: false, reason: `Input "${targetPort.label}" accepts only one connection` };
+    }
+  }
+
   if (!areTypesCompatible(sourcePort.type, targetPort.type)) {
     return {
       valid: false,
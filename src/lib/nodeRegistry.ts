@@ -18,6 +18,7 @@ export interface ConfigFieldSchema {
     | "select"
     | "file-path-open"
     | "file-path-save"
+    | "directory-path"
     | "key-value"
     | "model-select";
   options?: { label: string; value: string }[];
@@ -88,30 +89,61 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
     type: "textTemplate",
     label: "Text Template",
     category: "transform",
-    description: "Interpolate variables into a template string",
+    description: "Render a Jinja template with loops, conditionals and filters",
     inputs: [
       { id: "template", label: "Template", type: "string", required: true },
       { id: "variables", label: "Variables", type: "object", required: false },
     ],
     outputs: [{ id: "result", label: "Result", type: "string", required: false }],
-    defaultConfig: { template: "Hello, {{name}}!" },
+    defaultConfig: { template: "Hello, {{name}}!", strict: false, escape: "none" },
     configSchema: [
-      { key: "template", label: "Template", required: true, widget: "textarea", rows: 4, placeholder: "Hello, {{name}}!" },
+      { key: "template", label: "Template", required: true, widget: "textarea", rows: 4, placeholder: "Hello, {{ user.name }}!{% for item in items %} {{ item }}{% endfor %}" },
+      { key: "strict", label: "Fail on missing variables", widget: "checkbox" },
+      {
+        key: "escape",
+        label: "Escape Values",
+        widget: "select",
+        options: [
+          { label: "None", value: "none" },
+          { label: "HTML", value: "html" },
+          { label: "JSON", value: "json" },
+        ],
+      },
     ],
   },
   {
     type: "fileRead",
     label: "File Read",
     category: "input",
-    description: "Read contents from a file",
+    description: "Read text or binary contents from a file",
     inputs: [{ id: "path", label: "Path", type: "string", required: false }],
     outputs: [
       { id: "content", label: "Content", type: "string", required: false },
       { id: "file", label: "File", type: "file", required: false },
     ],
-    defaultConfig: { path: "" },
+    defaultConfig: { path: "", mode: "text", encoding: "utf-8" },
     configSchema: [
       { key: "path", label: "File Path", required: true, widget: "file-path-open", placeholder: "/path/to/file" },
+      {
+        key: "mode",
+        label: "Mode",
+        widget: "select",
+        options: [
+          { label: "Text", value: "text" },
+          { label: "Binary", value: "binary" },
+          { label: "Stream (large files)", value: "stream" },
+        ],
+      },
+      {
+        key: "encoding",
+        label: "Text Encoding",
+        widget: "select",
+        options: [
+          { label: "UTF-8", value: "utf-8" },
+          { label: "Latin-1", value: "latin-1" },
+          { label: "Auto-detect", value: "auto" },
+        ],
+      },
     ],
   },
   {
@@ -124,10 +156,62 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
       { id: "content", label: "Content", type: "string", required: true },
     ],
     outputs: [{ id: "file", label: "File", type: "file", required: false }],
-    defaultConfig: { path: "", append: false },
+    defaultConfig: { path: "", append: false, mode: "text", encoding: "utf-8" },
     configSchema: [
       { key: "path", label: "File Path", required: true, widget: "file-path-save", placeholder: "/path/to/file" },
       { key: "append", label: "Append to file", widget: "checkbox" },
+      {
+        key: "mode",
+        label: "Mode",
+        widget: "select",
+        options: [
+          { label: "Text", value: "text" },
+          { label: "Binary (bytes or base64)", value: "binary" },
+        ],
+      },
+      {
+        key: "encoding",
+        label: "Text Encoding",
+        widget: "select",
+        options: [
+          { label: "UTF-8", value: "utf-8" },
+          { label: "Latin-1", value: "latin-1" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "fileOps",
+    label: "File Ops",
+    category: "output",
+    description: "Copy, move, delete, create folders, check or inspect files",
+    inputs: [
+      { id: "path", label: "Path", type: "string", required: false },
+      { id: "destination", label: "Destination", type: "string", required: false },
+    ],
+    outputs: [
+      { id: "file", label: "File", type: "file", required: false },
+      { id: "result", label: "Result", type: "any", required: false },
+    ],
+    defaultConfig: { operation: "exists", path: "", destination: "", overwrite: false, recursive: false },
+    configSchema: [
+      {
+        key: "operation",
+        label: "Operation",
+        widget: "select",
+        options: [
+          { label: "Exists", value: "exists" },
+          { label: "Stat (size, modified time)", value: "stat" },
+          { label: "Create folder (with parents)", value: "mkdir" },
+          { label: "Copy", value: "copy" },
+          { label: "Move / rename", value: "move" },
+          { label: "Delete", value: "delete" },
+        ],
+      },
+      { key: "path", label: "Path", widget: "text", placeholder: "/path/to/file-or-folder" },
+      { key: "destination", label: "Destination (copy, move)", widget: "text", placeholder: "/path/to/target or folder" },
+      { key: "overwrite", label: "Overwrite an existing destination", widget: "checkbox" },
+      { key: "recursive", label: "Delete folders with their contents", widget: "checkbox" },
     ],
   },
   {
@@ -143,7 +227,7 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
       { id: "response", label: "Response", type: "string", required: false },
       { id: "status", label: "Status", type: "number", required: false },
     ],
-    defaultConfig: { url: "", method: "GET", headers: "{}" },
+    defaultConfig: { url: "", method: "GET", headers: "{}", responseType: "text" },
     configSchema: [
       { key: "url", label: "URL", required: true, widget: "text", placeholder: "https://api.example.com" },
       {
@@ -159,6 +243,57 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
         ],
       },
       { key: "headers", label: "Headers", widget: "key-value" },
+      {
+        key: "responseType",
+        label: "Response Type",
+        widget: "select",
+        options: [
+          { label: "Text", value: "text" },
+          { label: "Binary", value: "binary" },
+          { label: "Auto (by Content-Type)", value: "auto" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "uuid",
+    label: "UUID",
+    category: "input",
+    description: "Generate random (v4) or time-ordered (v7) UUIDs",
+    inputs: [],
+    outputs: [{ id: "value", label: "UUID", type: "any", required: false }],
+    defaultConfig: { version: "v4", count: "" },
+    configSchema: [
+      {
+        key: "version",
+        label: "Version",
+        widget: "select",
+        options: [
+          { label: "v4 (random)", value: "v4" },
+          { label: "v7 (time-ordered)", value: "v7" },
+        ],
+      },
+      { key: "count", label: "Count", widget: "number", min: 1, max: 100000, step: 1 },
+    ],
+  },
+  {
+    type: "directoryList",
+    label: "Directory List",
+    category: "input",
+    description: "List the files in a folder or a glob path like data/**/*.csv, optionally filtered by patterns",
+    inputs: [{ id: "path", label: "Folder", type: "string", required: false }],
+    outputs: [
+      { id: "files", label: "Files", type: "array", required: false },
+      { id: "entries", label: "Entries", type: "array", required: false },
+      { id: "count", label: "Count", type: "number", required: false },
+    ],
+    defaultConfig: { path: "", recursive: false, include: "", exclude: "", includeDirs: false },
+    configSchema: [
+      { key: "path", label: "Folder", required: true, widget: "directory-path" },
+      { key: "recursive", label: "Include subfolders", widget: "checkbox" },
+      { key: "include", label: "Include", widget: "text", placeholder: "*.csv, reports/**/*.json" },
+      { key: "exclude", label: "Exclude", widget: "text", placeholder: "node_modules, *.tmp" },
+      { key: "includeDirs", label: "List folders too", widget: "checkbox" },
     ],
   },
   {
@@ -171,20 +306,43 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
     defaultConfig: {},
     configSchema: [],
   },
+  {
+    type: "jsonSerialize",
+    label: "JSON Serialize",
+    category: "transform",
+    description: "Convert a value to pretty or compact JSON text",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [{ id: "output", label: "JSON", type: "string", required: false }],
+    defaultConfig: { format: "pretty" },
+    configSchema: [
+      {
+        key: "format",
+        label: "Format",
+        widget: "select",
+        options: [
+          { label: "Pretty", value: "pretty" },
+          { label: "Compact", value: "compact" },
+        ],
+      },
+    ],
+  },
   {
     type: "regex",
     label: "Regex",
     category: "transform",
-    description: "Match or replace using regular expressions",
-    inputs: [{ id: "input", label: "Input", type: "string", required: true }],
+    description: "Match, capture, replace or split using regular expressions",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
     outputs: [
       { id: "matches", label: "Matches", type: "array", required: false },
+      { id: "captures", label: "Captures", type: "array", required: false },
+      { id: "isMatch", label: "Is Match", type: "boolean", required: false },
       { id: "result", label: "Result", type: "string", required: false },
+      { id: "parts", label: "Parts", type: "array", required: false },
     ],
-    defaultConfig: { pattern: "", flags: "g", mode: "match" },
+    defaultConfig: { pattern: "", flags: "g", mode: "match", replacement: "" },
     configSchema: [
-      { key: "pattern", label: "Pattern", required: true, widget: "text", placeholder: "\\w+" },
-      { key: "flags", label: "Flags", widget: "text", placeholder: "g, i, m, s" },
+      { key: "pattern", label: "Pattern", required: true, widget: "text", placeholder: "(?P<year>\\d{4})-(\\d{2})" },
+      { key: "flags", label: "Flags", widget: "text", placeholder: "g (all), i, m, s, x" },
       {
         key: "mode",
         label: "Mode",
@@ -192,8 +350,10 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
         options: [
           { label: "Match", value: "match" },
           { label: "Replace", value: "replace" },
+          { label: "Split", value: "split" },
         ],
       },
+      { key: "replacement", label: "Replacement", widget: "text", placeholder: "$1 or ${name}" },
     ],
   },
   {
@@ -227,8 +387,9 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
     category: "transform",
     description: "Merge multiple inputs into one output",
     inputs: [
-      { id: "a", label: "A", type: "any", required: true },
-      { id: "b", label: "B", type: "any", required: true },
+      { id: "a", label: "A", type: "any", required: false },
+      { id: "b", label: "B", type: "any", required: false },
+      { id: "inputs", label: "Inputs", type: "any", required: false, multiple: true },
     ],
     outputs: [{ id: "output", label: "Output", type: "array", required: false }],
     defaultConfig: {},
@@ -257,6 +418,786 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
       },
     ],
   },
+  {
+    type: "csvParse",
+    label: "CSV Parse",
+    category: "transform",
+    description: "Parse CSV or TSV text into an array of rows",
+    inputs: [{ id: "input", label: "Input", type: "string", required: true }],
+    outputs: [
+      { id: "output", label: "Rows", type: "array", required: false },
+      { id: "columns", label: "Columns", type: "array", required: false },
+    ],
+    defaultConfig: { delimiter: ",", quote: "\"", hasHeader: true, inferTypes: true },
+    configSchema: [
+      {
+        key: "delimiter",
+        label: "Delimiter",
+        widget: "select",
+        options: [
+          { label: "Comma (,)", value: "," },
+          { label: "Tab (\\t)", value: "\t" },
+          { label: "Semicolon (;)", value: ";" },
+          { label: "Pipe (|)", value: "|" },
+        ],
+      },
+      { key: "quote", label: "Quote Character", widget: "text", placeholder: "\"" },
+      { key: "hasHeader", label: "First row is header", widget: "checkbox" },
+      { key: "inferTypes", label: "Infer numbers, booleans and empty cells", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "csvSerialize",
+    label: "CSV Serialize",
+    category: "transform",
+    description: "Write an array of rows as CSV or TSV text",
+    inputs: [{ id: "input", label: "Rows", type: "array", required: true }],
+    outputs: [{ id: "output", label: "CSV", type: "string", required: false }],
+    defaultConfig: { delimiter: ",", columns: "", includeHeader: true },
+    configSchema: [
+      {
+        key: "delimiter",
+        label: "Delimiter",
+        widget: "select",
+        options: [
+          { label: "Comma (,)", value: "," },
+          { label: "Tab (\\t)", value: "\t" },
+          { label: "Semicolon (;)", value: ";" },
+          { label: "Pipe (|)", value: "|" },
+        ],
+      },
+      { key: "columns", label: "Columns (in order)", widget: "text", placeholder: "name, email, age" },
+      { key: "includeHeader", label: "Include header row", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "yamlParse",
+    label: "YAML Parse",
+    category: "transform",
+    description: "Parse a YAML document into a value",
+    inputs: [{ id: "input", label: "Input", type: "string", required: true }],
+    outputs: [{ id: "output", label: "Output", type: "any", required: false }],
+    defaultConfig: { multiDocument: false },
+    configSchema: [
+      { key: "multiDocument", label: "Multiple documents (output an array)", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "yamlSerialize",
+    label: "YAML Serialize",
+    category: "transform",
+    description: "Convert a value to YAML text",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [{ id: "output", label: "YAML", type: "string", required: false }],
+    defaultConfig: {},
+    configSchema: [],
+  },
+  {
+    type: "tomlParse",
+    label: "TOML Parse",
+    category: "transform",
+    description: "Parse a TOML document into an object",
+    inputs: [{ id: "input", label: "Input", type: "string", required: true }],
+    outputs: [{ id: "output", label: "Output", type: "object", required: false }],
+    defaultConfig: {},
+    configSchema: [],
+  },
+  {
+    type: "tomlSerialize",
+    label: "TOML Serialize",
+    category: "transform",
+    description: "Convert an object to TOML text",
+    inputs: [{ id: "input", label: "Input", type: "object", required: true }],
+    outputs: [{ id: "output", label: "TOML", type: "string", required: false }],
+    defaultConfig: {},
+    configSchema: [],
+  },
+  {
+    type: "xmlParse",
+    label: "XML Parse",
+    category: "transform",
+    description: "Parse XML into an object, mapping attributes and text to keys",
+    inputs: [{ id: "input", label: "Input", type: "string", required: true }],
+    outputs: [{ id: "output", label: "Output", type: "object", required: false }],
+    defaultConfig: { attributePrefix: "@", textKey: "#text", inferTypes: false },
+    configSchema: [
+      { key: "attributePrefix", label: "Attribute Prefix", widget: "text", placeholder: "@" },
+      { key: "textKey", label: "Text Key", widget: "text", placeholder: "#text" },
+      { key: "inferTypes", label: "Infer numbers and booleans", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "xmlSerialize",
+    label: "XML Serialize",
+    category: "transform",
+    description: "Convert an object to XML text",
+    inputs: [{ id: "input", label: "Input", type: "object", required: true }],
+    outputs: [{ id: "output", label: "XML", type: "string", required: false }],
+    defaultConfig: { rootName: "", attributePrefix: "@", textKey: "#text", pretty: true, declaration: true },
+    configSchema: [
+      { key: "rootName", label: "Root Element", widget: "text", placeholder: "Use the input's single key" },
+      { key: "attributePrefix", label: "Attribute Prefix", widget: "text", placeholder: "@" },
+      { key: "textKey", label: "Text Key", widget: "text", placeholder: "#text" },
+      { key: "pretty", label: "Indent output", widget: "checkbox" },
+      { key: "declaration", label: "Include XML declaration", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "query",
+    label: "Query",
+    category: "transform",
+    description: "Extract values with a JSONPath query",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [
+      { id: "output", label: "Result", type: "any", required: false },
+      { id: "count", label: "Matches", type: "number", required: false },
+    ],
+    defaultConfig: { query: "", mode: "auto" },
+    configSchema: [
+      { key: "query", label: "Query", required: true, widget: "text", placeholder: "$.data.items[*].name" },
+      {
+        key: "mode",
+        label: "Result",
+        widget: "select",
+        options: [
+          { label: "Auto (single for plain paths)", value: "auto" },
+          { label: "First match", value: "first" },
+          { label: "All matches (array)", value: "all" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "sort",
+    label: "Sort",
+    category: "transform",
+    description: "Sort an array by a field",
+    inputs: [{ id: "input", label: "Input", type: "array", required: true }],
+    outputs: [{ id: "output", label: "Sorted", type: "array", required: false }],
+    defaultConfig: { field: "", order: "asc", compare: "auto" },
+    configSchema: [
+      { key: "field", label: "Field Path", widget: "text", placeholder: "user.name (empty: the item itself)" },
+      {
+        key: "order",
+        label: "Order",
+        widget: "select",
+        options: [
+          { label: "Ascending", value: "asc" },
+          { label: "Descending", value: "desc" },
+        ],
+      },
+      {
+        key: "compare",
+        label: "Compare As",
+        widget: "select",
+        options: [
+          { label: "Auto", value: "auto" },
+          { label: "Numbers", value: "numeric" },
+          { label: "Text", value: "string" },
+          { label: "Natural (file2 before file10)", value: "natural" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "groupBy",
+    label: "Group By",
+    category: "transform",
+    description: "Group array items by a key field",
+    inputs: [{ id: "input", label: "Input", type: "array", required: true }],
+    outputs: [
+      { id: "output", label: "By Key", type: "object", required: false },
+      { id: "groups", label: "Groups", type: "array", required: false },
+    ],
+    defaultConfig: { key: "" },
+    configSchema: [
+      { key: "key", label: "Key Field", required: true, widget: "text", placeholder: "customer.country" },
+    ],
+  },
+  {
+    type: "aggregate",
+    label: "Aggregate",
+    category: "transform",
+    description: "Count, sum, average, min, max or collect distinct values per group",
+    inputs: [{ id: "input", label: "Input", type: "array", required: true }],
+    outputs: [{ id: "output", label: "Output", type: "any", required: false }],
+    defaultConfig: { groupBy: "", aggregations: "count()" },
+    configSchema: [
+      { key: "groupBy", label: "Group By Field", widget: "text", placeholder: "region (empty: whole array)" },
+      {
+        key: "aggregations",
+        label: "Aggregations",
+        required: true,
+        widget: "text",
+        placeholder: "count(), sum(amount), avg(amount) as mean",
+      },
+    ],
+  },
+  {
+    type: "dedupe",
+    label: "Dedupe",
+    category: "transform",
+    description: "Remove duplicate items by a key field",
+    inputs: [{ id: "input", label: "Input", type: "array", required: true }],
+    outputs: [
+      { id: "output", label: "Unique", type: "array", required: false },
+      { id: "removed", label: "Removed", type: "number", required: false },
+    ],
+    defaultConfig: { key: "", keep: "first" },
+    configSchema: [
+      { key: "key", label: "Key Field", widget: "text", placeholder: "email (empty: whole item)" },
+      {
+        key: "keep",
+        label: "Keep",
+        widget: "select",
+        options: [
+          { label: "First occurrence", value: "first" },
+          { label: "Last occurrence", value: "last" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "join",
+    label: "Join",
+    category: "transform",
+    description: "Join two arrays of records on matching keys",
+    inputs: [
+      { id: "left", label: "Left", type: "array", required: true },
+      { id: "right", label: "Right", type: "array", required: true },
+    ],
+    outputs: [{ id: "output", label: "Joined", type: "array", required: false }],
+    defaultConfig: { leftKey: "", rightKey: "", mode: "inner", conflict: "prefix", rightPrefix: "right_" },
+    configSchema: [
+      { key: "leftKey", label: "Left Key", required: true, widget: "text", placeholder: "id" },
+      { key: "rightKey", label: "Right Key", widget: "text", placeholder: "Same as left key" },
+      {
+        key: "mode",
+        label: "Join Type",
+        widget: "select",
+        options: [
+          { label: "Inner (matches only)", value: "inner" },
+          { label: "Left (all left records)", value: "left" },
+          { label: "Right (all right records)", value: "right" },
+          { label: "Full (everything)", value: "full" },
+        ],
+      },
+      {
+        key: "conflict",
+        label: "Same Field, Different Values",
+        widget: "select",
+        options: [
+          { label: "Keep both (prefix right field)", value: "prefix" },
+          { label: "Keep left value", value: "left" },
+          { label: "Keep right value", value: "right" },
+          { label: "Fail", value: "error" },
+        ],
+      },
+      { key: "rightPrefix", label: "Right Field Prefix", widget: "text", placeholder: "right_" },
+    ],
+  },
+  {
+    type: "objectTransform",
+    label: "Object Transform",
+    category: "transform",
+    description: "Pick, omit, rename, set or flatten object fields",
+    inputs: [{ id: "input", label: "Object", type: "any", required: true }],
+    outputs: [{ id: "output", label: "Result", type: "any", required: false }],
+    defaultConfig: { operation: "pick", fields: "", separator: ".", flattenArrays: false },
+    configSchema: [
+      {
+        key: "operation",
+        label: "Operation",
+        widget: "select",
+        options: [
+          { label: "Pick fields", value: "pick" },
+          { label: "Omit fields", value: "omit" },
+          { label: "Rename fields (old=new)", value: "rename" },
+          { label: "Set fields (field=value)", value: "set" },
+          { label: "Flatten nested objects", value: "flatten" },
+          { label: "Unflatten keys", value: "unflatten" },
+        ],
+      },
+      { key: "fields", label: "Fields", widget: "textarea", rows: 3, placeholder: "name.first, email" },
+      { key: "separator", label: "Key Separator (flatten, unflatten)", widget: "text", placeholder: "." },
+      { key: "flattenArrays", label: "Flatten arrays into index keys", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "flatten",
+    label: "Flatten",
+    category: "transform",
+    description: "Flatten nested arrays into one",
+    inputs: [{ id: "input", label: "Array", type: "array", required: true }],
+    outputs: [{ id: "output", label: "Flattened", type: "array", required: false }],
+    defaultConfig: { depth: 1 },
+    configSchema: [
+      { key: "depth", label: "Depth (0 for all levels)", widget: "number", min: 0, step: 1 },
+    ],
+  },
+  {
+    type: "chunk",
+    label: "Chunk",
+    category: "transform",
+    description: "Split an array into batches of N items",
+    inputs: [{ id: "input", label: "Array", type: "array", required: true }],
+    outputs: [
+      { id: "output", label: "Batches", type: "array", required: false },
+      { id: "count", label: "Batch Count", type: "number", required: false },
+    ],
+    defaultConfig: { size: 10 },
+    configSchema: [
+      { key: "size", label: "Batch Size", required: true, widget: "number", min: 1, step: 1 },
+    ],
+  },
+  {
+    type: "zip",
+    label: "Zip",
+    category: "transform",
+    description: "Pair up the items of two arrays",
+    inputs: [
+      { id: "left", label: "Left", type: "array", required: true },
+      { id: "right", label: "Right", type: "array", required: true },
+    ],
+    outputs: [{ id: "output", label: "Pairs", type: "array", required: false }],
+    defaultConfig: { length: "shortest", names: "" },
+    configSchema: [
+      {
+        key: "length",
+        label: "Length",
+        widget: "select",
+        options: [
+          { label: "Stop at the shorter array", value: "shortest" },
+          { label: "Pad the shorter array with null", value: "longest" },
+        ],
+      },
+      { key: "names", label: "Field Names", widget: "text", placeholder: "Leave empty for [left, right] pairs" },
+    ],
+  },
+  {
+    type: "slice",
+    label: "Slice",
+    category: "transform",
+    description: "Take or skip items of an array",
+    inputs: [{ id: "input", label: "Array", type: "array", required: true }],
+    outputs: [{ id: "output", label: "Items", type: "array", required: false }],
+    defaultConfig: { start: 0, end: "" },
+    configSchema: [
+      { key: "start", label: "Start (skip N)", widget: "number", step: 1 },
+      { key: "end", label: "End (take up to N, exclusive)", widget: "number", step: 1 },
+    ],
+  },
+  {
+    type: "reverse",
+    label: "Reverse",
+    category: "transform",
+    description: "Reverse the order of an array",
+    inputs: [{ id: "input", label: "Array", type: "array", required: true }],
+    outputs: [{ id: "output", label: "Reversed", type: "array", required: false }],
+    defaultConfig: {},
+    configSchema: [],
+  },
+  {
+    type: "unique",
+    label: "Unique",
+    category: "transform",
+    description: "Distinct values of an array or field",
+    inputs: [{ id: "input", label: "Array", type: "array", required: true }],
+    outputs: [
+      { id: "output", label: "Values", type: "array", required: false },
+      { id: "count", label: "Count", type: "number", required: false },
+    ],
+    defaultConfig: { field: "" },
+    configSchema: [
+      { key: "field", label: "Field", widget: "text", placeholder: "Leave empty for whole items" },
+    ],
+  },
+  {
+    type: "length",
+    label: "Length",
+    category: "transform",
+    description: "Count items, characters or fields",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [{ id: "output", label: "Length", type: "number", required: false }],
+    defaultConfig: {},
+    configSchema: [],
+  },
+  {
+    type: "reduce",
+    label: "Reduce",
+    category: "transform",
+    description: "Fold an array into one value with an accumulator",
+    inputs: [{ id: "input", label: "Array", type: "array", required: true }],
+    outputs: [
+      { id: "output", label: "Result", type: "any", required: false },
+      { id: "steps", label: "Steps", type: "array", required: false },
+    ],
+    defaultConfig: { language: "expression", initial: "0", expression: "acc + item", emitSteps: false },
+    configSchema: [
+      {
+        key: "language",
+        label: "Accumulator",
+        widget: "select",
+        options: [
+          { label: "Expression", value: "expression" },
+          { label: "Rhai script", value: "script" },
+        ],
+      },
+      { key: "initial", label: "Initial Value", widget: "text", placeholder: "0, {}, [] or text" },
+      { key: "expression", label: "Next Accumulator", required: true, widget: "textarea", rows: 3, monospace: true, placeholder: "acc + item.amount" },
+      { key: "emitSteps", label: "Output every intermediate value", widget: "checkbox" },
+    ],
+  },
+  {
+    type: "textTransform",
+    label: "Text Transform",
+    category: "transform",
+    description: "Change case, trim, pad, cut, replace or slugify text",
+    inputs: [{ id: "input", label: "Text", type: "any", required: true }],
+    outputs: [{ id: "output", label: "Result", type: "any", required: false }],
+    defaultConfig: { operation: "trim", width: 0, padChar: " ", start: 0, end: "", find: "", replacement: "", replaceAll: true, form: "NFC" },
+    configSchema: [
+      {
+        key: "operation",
+        label: "Operation",
+        widget: "select",
+        options: [
+          { label: "UPPER CASE", value: "upper" },
+          { label: "lower case", value: "lower" },
+          { label: "Title Case", value: "title" },
+          { label: "Trim", value: "trim" },
+          { label: "Trim start", value: "trimStart" },
+          { label: "Trim end", value: "trimEnd" },
+          { label: "Pad start", value: "padStart" },
+          { label: "Pad end", value: "padEnd" },
+          { label: "Substring", value: "substring" },
+          { label: "Find and replace", value: "replace" },
+          { label: "Length", value: "length" },
+          { label: "Slugify", value: "slugify" },
+          { label: "Normalize whitespace", value: "normalizeWhitespace" },
+          { label: "Unicode normalization", value: "normalize" },
+        ],
+      },
+      { key: "width", label: "Pad Width", widget: "number", min: 0, step: 1 },
+      { key: "padChar", label: "Pad Character", widget: "text", placeholder: "Space" },
+      { key: "start", label: "Substring Start", widget: "number", step: 1 },
+      { key: "end", label: "Substring End (exclusive)", widget: "number", step: 1 },
+      { key: "find", label: "Find", widget: "text", placeholder: "Literal text" },
+      { key: "replacement", label: "Replace With", widget: "text" },
+      { key: "replaceAll", label: "Replace every occurrence", widget: "checkbox" },
+      {
+        key: "form",
+        label: "Unicode Form",
+        widget: "select",
+        options: [
+          { label: "NFC (composed)", value: "NFC" },
+          { label: "NFD (decomposed)", value: "NFD" },
+          { label: "NFKC (compatibility, composed)", value: "NFKC" },
+          { label: "NFKD (compatibility, decomposed)", value: "NFKD" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "math",
+    label: "Math",
+    category: "transform",
+    description: "Calculate with numbers and format the result",
+    inputs: [
+      { id: "a", label: "A", type: "any", required: true },
+      { id: "b", label: "B", type: "any", required: false },
+      { id: "c", label: "C", type: "any", required: false },
+    ],
+    outputs: [
+      { id: "result", label: "Result", type: "number", required: false },
+      { id: "formatted", label: "Formatted", type: "string", required: false },
+    ],
+    defaultConfig: { expression: "a", decimals: "", thousands: "", decimalPoint: ".", style: "number", prefix: "", suffix: "" },
+    configSchema: [
+      { key: "expression", label: "Expression", widget: "text", placeholder: "round(a * b, 2)" },
+      { key: "decimals", label: "Decimals", widget: "number", min: 0, max: 20, step: 1 },
+      { key: "thousands", label: "Thousands Separator", widget: "text", placeholder: "None, or , or space" },
+      { key: "decimalPoint", label: "Decimal Point", widget: "text", placeholder: "." },
+      {
+        key: "style",
+        label: "Style",
+        widget: "select",
+        options: [
+          { label: "Number", value: "number" },
+          { label: "Percentage (x 100, with %)", value: "percent" },
+        ],
+      },
+      { key: "prefix", label: "Prefix", widget: "text", placeholder: "$" },
+      { key: "suffix", label: "Suffix", widget: "text", placeholder: " EUR" },
+    ],
+  },
+  {
+    type: "dateTime",
+    label: "Date & Time",
+    category: "transform",
+    description: "Get, parse, format, shift, compare or convert dates",
+    inputs: [
+      { id: "input", label: "Date", type: "any", required: false },
+      { id: "other", label: "Other Date", type: "any", required: false },
+    ],
+    outputs: [{ id: "output", label: "Result", type: "any", required: false }],
+    defaultConfig: { operation: "now", inputFormat: "", format: "", timezone: "", amount: "1d", unit: "days" },
+    configSchema: [
+      {
+        key: "operation",
+        label: "Operation",
+        widget: "select",
+        options: [
+          { label: "Current time", value: "now" },
+          { label: "Parse", value: "parse" },
+          { label: "Format", value: "format" },
+          { label: "Add or subtract", value: "add" },
+          { label: "Difference (date - other)", value: "diff" },
+          { label: "Convert time zone", value: "convert" },
+        ],
+      },
+      { key: "inputFormat", label: "Input Format", widget: "text", placeholder: "Auto, %d/%m/%Y, unix or unixMs" },
+      { key: "format", label: "Output Format", widget: "text", placeholder: "%Y-%m-%d %H:%M:%S, unix or unixMs" },
+      { key: "timezone", label: "Time Zone", widget: "text", placeholder: "UTC, Europe/Paris, America/New_York" },
+      { key: "amount", label: "Duration", widget: "text", placeholder: "1d 12h, -30m, 1mo" },
+      {
+        key: "unit",
+        label: "Difference Unit",
+        widget: "select",
+        options: [
+          { label: "Milliseconds", value: "milliseconds" },
+          { label: "Seconds", value: "seconds" },
+          { label: "Minutes", value: "minutes" },
+          { label: "Hours", value: "hours" },
+          { label: "Days", value: "days" },
+          { label: "Weeks", value: "weeks" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "encode",
+    label: "Encode / Decode",
+    category: "transform",
+    description: "Convert text or bytes to and from base64, hex or URL encoding",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [{ id: "output", label: "Output", type: "any", required: false }],
+    defaultConfig: { encoding: "base64", direction: "encode", output: "text" },
+    configSchema: [
+      {
+        key: "encoding",
+        label: "Encoding",
+        widget: "select",
+        options: [
+          { label: "Base64", value: "base64" },
+          { label: "Base64 (URL-safe)", value: "base64url" },
+          { label: "Hex", value: "hex" },
+          { label: "URL (percent)", value: "url" },
+        ],
+      },
+      {
+        key: "direction",
+        label: "Direction",
+        widget: "select",
+        options: [
+          { label: "Encode", value: "encode" },
+          { label: "Decode", value: "decode" },
+        ],
+      },
+      {
+        key: "output",
+        label: "Decoded Output",
+        widget: "select",
+        options: [
+          { label: "Text (UTF-8)", value: "text" },
+          { label: "Bytes", value: "bytes" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "hash",
+    label: "Hash",
+    category: "transform",
+    description: "Compute a SHA-256, SHA-512, SHA-1, MD5 or BLAKE3 digest",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [{ id: "output", label: "Digest", type: "any", required: false }],
+    defaultConfig: { algorithm: "sha256", encoding: "hex" },
+    configSchema: [
+      {
+        key: "algorithm",
+        label: "Algorithm",
+        widget: "select",
+        options: [
+          { label: "SHA-256", value: "sha256" },
+          { label: "SHA-512", value: "sha512" },
+          { label: "SHA-1", value: "sha1" },
+          { label: "MD5", value: "md5" },
+          { label: "BLAKE3", value: "blake3" },
+        ],
+      },
+      {
+        key: "encoding",
+        label: "Output",
+        widget: "select",
+        options: [
+          { label: "Hex", value: "hex" },
+          { label: "Base64", value: "base64" },
+          { label: "Bytes", value: "bytes" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "hmac",
+    label: "HMAC",
+    category: "transform",
+    description: "Sign data with a secret key using HMAC",
+    inputs: [
+      { id: "input", label: "Data", type: "any", required: true },
+      { id: "key", label: "Key", type: "any", required: true },
+    ],
+    outputs: [{ id: "output", label: "Signature", type: "any", required: false }],
+    defaultConfig: { algorithm: "sha256", encoding: "hex", keyEncoding: "text" },
+    configSchema: [
+      {
+        key: "algorithm",
+        label: "Algorithm",
+        widget: "select",
+        options: [
+          { label: "SHA-256", value: "sha256" },
+          { label: "SHA-512", value: "sha512" },
+          { label: "SHA-1", value: "sha1" },
+          { label: "MD5", value: "md5" },
+        ],
+      },
+      {
+        key: "keyEncoding",
+        label: "Key Format",
+        widget: "select",
+        options: [
+          { label: "Text", value: "text" },
+          { label: "Hex", value: "hex" },
+          { label: "Base64", value: "base64" },
+        ],
+      },
+      {
+        key: "encoding",
+        label: "Output",
+        widget: "select",
+        options: [
+          { label: "Hex", value: "hex" },
+          { label: "Base64", value: "base64" },
+          { label: "Bytes", value: "bytes" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "compress",
+    label: "Compress",
+    category: "transform",
+    description: "Compress or decompress data with gzip or zstd",
+    inputs: [{ id: "input", label: "Input", type: "any", required: true }],
+    outputs: [{ id: "output", label: "Output", type: "any", required: false }],
+    defaultConfig: { format: "gzip", direction: "compress", level: "", output: "auto" },
+    configSchema: [
+      {
+        key: "format",
+        label: "Format",
+        widget: "select",
+        options: [
+          { label: "gzip", value: "gzip" },
+          { label: "zstd", value: "zstd" },
+        ],
+      },
+      {
+        key: "direction",
+        label: "Direction",
+        widget: "select",
+        options: [
+          { label: "Compress", value: "compress" },
+          { label: "Decompress", value: "decompress" },
+        ],
+      },
+      { key: "level", label: "Level (gzip 0-9, zstd 1-22)", widget: "number", min: 0, max: 22, step: 1 },
+      {
+        key: "output",
+        label: "Decompressed Output",
+        widget: "select",
+        options: [
+          { label: "Auto (text if UTF-8)", value: "auto" },
+          { label: "Text", value: "text" },
+          { label: "Bytes", value: "bytes" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "archiveExtract",
+    label: "Archive Extract",
+    category: "transform",
+    description: "List or extract the files in a zip or tar archive",
+    inputs: [{ id: "input", label: "Archive", type: "any", required: true }],
+    outputs: [
+      { id: "entries", label: "Entries", type: "array", required: false },
+      { id: "count", label: "Count", type: "number", required: false },
+    ],
+    defaultConfig: { format: "auto", mode: "extract", output: "auto" },
+    configSchema: [
+      {
+        key: "format",
+        label: "Format",
+        widget: "select",
+        options: [
+          { label: "Auto-detect", value: "auto" },
+          { label: "zip", value: "zip" },
+          { label: "tar", value: "tar" },
+          { label: "tar.gz", value: "tar.gz" },
+        ],
+      },
+      {
+        key: "mode",
+        label: "Mode",
+        widget: "select",
+        options: [
+          { label: "Extract {name, content}", value: "extract" },
+          { label: "List {name, size}", value: "list" },
+        ],
+      },
+      {
+        key: "output",
+        label: "Content",
+        widget: "select",
+        options: [
+          { label: "Auto (text if UTF-8)", value: "auto" },
+          { label: "Text", value: "text" },
+          { label: "Bytes", value: "bytes" },
+        ],
+      },
+    ],
+  },
+  {
+    type: "archiveCreate",
+    label: "Archive Create",
+    category: "transform",
+    description: "Build a zip or tar archive from name and content pairs",
+    inputs: [{ id: "entries", label: "Entries", type: "array", required: true }],
+    outputs: [{ id: "output", label: "Archive", type: "any", required: false }],
+    defaultConfig: { format: "zip" },
+    configSchema: [
+      {
+        key: "format",
+        label: "Format",
+        widget: "select",
+        options: [
+          { label: "zip", value: "zip" },
+          { label: "tar", value: "tar" },
+          { label: "tar.gz", value: "tar.gz" },
+        ],
+      },
+    ],
+  },
   {
     type: "conditional",
     label: "Conditional",
@@ -313,12 +1254,16 @@ export const NODE_DEFINITIONS: NodeDefinitionMeta[] = [
     type: "code",
     label: "Code",
     category: "code",
-    description: "Run custom JavaScript code",
+    description: "Run a custom Rhai script",
     inputs: [{ id: "input", label: "Input", type: "any", required: false }],
-    outputs: [{ id: "output", label: "Output", type: "any", required: false }],
-    defaultConfig: { code: "return input;" },
+    outputs: [
+      { id: "output", label: "Output", type: "any", required: false },
+      { id: "logs", label: "Logs", type: "array", required: false },
+    ],
+    defaultConfig: { code: "return input;", timeoutMs: 5000 },
     configSchema: [
       { key: "code", label: "Code", required: true, widget: "textarea", rows: 6, monospace: true, placeholder: "return input;" },
+      { key: "timeoutMs", label: "Timeout (ms)", widget: "number", min: 100, max: 60000, step: 100 },
     ],
   },
 ];
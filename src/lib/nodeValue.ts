@@ -0,0 +1,28 @@
+// Binary values arrive from the backend as `{ "$type": "bytes", "base64": "..." }`
+export function bytesLength(value: unknown): number | null {
+  if (typeof value !== "object" || value === null) return null;
+  const tagged = value as Record<string, unknown>;
+  if (tagged.$type !== "bytes" || typeof tagged.base64 !== "string") return null;
+  const b64 = tagged.base64;
+  const padding = b64.endsWith("==") ? 2 : b64.endsWith("=") ? 1 : 0;
+  return Math.floor((b64.length * 3) / 4) - padding;
+}
+
+// Streams are read lazily by the backend and only described in output data
+export function streamDescription(value: unknown): string | null {
+  if (typeof value !== "object" || value === null) return null;
+  const tagged = value as Record<string, unknown>;
+  if (tagged.$type !== "stream" || typeof tagged.description !== "string") return null;
+  return tagged.description;
+}
+
+export function formatByteSize(size: number): string {
+  const units = ["B", "KB", "MB", "GB"];
+  let value = size;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return unit === 0 ? `${size} B` : `${value.toFixed(1)} ${units[unit]}`;
+}
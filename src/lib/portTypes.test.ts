@@ -20,6 +20,17 @@ describe("areTypesCompatible", () => {
     expect(areTypesCompatible("boolean", "string")).toBe(true);
   });
 
+  it("file can connect to string as its path", () => {
+    expect(areTypesCompatible("file", "string")).toBe(true);
+    expect(areTypesCompatible("string", "file")).toBe(false);
+  });
+
+  it("bytes and string connect both ways", () => {
+    expect(areTypesCompatible("bytes", "string")).toBe(true);
+    expect(areTypesCompatible("string", "bytes")).toBe(true);
+    expect(areTypesCompatible("bytes", "number")).toBe(false);
+  });
+
   it("string cannot connect to number", () => {
     expect(areTypesCompatible("string", "number")).toBe(false);
   });
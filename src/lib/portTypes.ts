@@ -5,6 +5,7 @@ export type PortType =
   | "array"
   | "object"
   | "file"
+  | "bytes"
   | "any";
 
 export interface PortDefinition {
@@ -12,6 +13,8 @@ export interface PortDefinition {
   label: string;
   type: PortType;
   required: boolean;
+  // Accepts several connections, delivered to the node as an ordered array
+  multiple?: boolean;
 }
 
 export const PORT_COLORS: Record<PortType, string> = {
@@ -21,6 +24,7 @@ export const PORT_COLORS: Record<PortType, string> = {
   array: "#a855f7",
   object: "#ec4899",
   file: "#6b7280",
+  bytes: "#f97316",
   any: "#e2e4e9",
 };
 
@@ -33,5 +37,10 @@ export function areTypesCompatible(
   // Number can connect to string (will be coerced)
   if (source === "number" && target === "string") return true;
   if (source === "boolean" && target === "string") return true;
+  // A File value stands for its path, so it can feed any path or text input
+  if (source === "file" && target === "string") return true;
+  // Binary-capable ports accept strings and vice versa (decoded or encoded by the node)
+  if (source === "string" && target === "bytes") return true;
+  if (source === "bytes" && target === "string") return true;
   return false;
 }
@@ -36,6 +36,7 @@ export interface ExecutionEvent {
 export interface ExecutionResult {
   success: boolean;
   total_duration_ms: number;
+  peak_retained_bytes: number;
   node_results: Record<
     string,
     {